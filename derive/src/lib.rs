@@ -0,0 +1,83 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, LitInt};
+
+/// Derives `IntoApiError` and `IntoResponse` for a domain error type.
+///
+/// Each enum variant may be annotated with `#[status(404)]`; the type itself may carry a
+/// `#[status(...)]` used as the default for unannotated variants (and for structs). Without
+/// any annotation the status is 500. The status picks the matching `ApiError` variant, e.g.
+/// `NotFound` for 404, and `Other` for codes without one. The error's `Display` output, e.g.
+/// from `thiserror`, becomes the message.
+#[proc_macro_derive(IntoApiError, attributes(status))]
+pub fn derive_into_api_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let default_status = status_attr(&input.attrs)?.unwrap_or(500);
+
+    let status = match &input.data {
+        Data::Enum(data) if data.variants.is_empty() => quote! { match *self {} },
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let status = status_attr(&variant.attrs)?.unwrap_or(default_status);
+                    Ok(quote! { Self::#ident { .. } => #status })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! { match self { #(#arms,)* } }
+        }
+        Data::Struct(_) => quote! { #default_status },
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                name,
+                "IntoApiError cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::skyak_axum_core::errors::IntoApiError for #name #ty_generics #where_clause {
+            fn to_api_error(&self) -> ::skyak_axum_core::errors::ApiError {
+                let status: u16 = #status;
+                ::skyak_axum_core::__private::api_error(
+                    status,
+                    ::std::string::ToString::to_string(self),
+                )
+            }
+        }
+
+        impl #impl_generics ::skyak_axum_core::__private::axum::response::IntoResponse for #name #ty_generics #where_clause {
+            fn into_response(self) -> ::skyak_axum_core::__private::axum::response::Response {
+                ::skyak_axum_core::errors::IntoApiError::to_api_response(&self)
+            }
+        }
+    })
+}
+
+/// Reads `#[status(NNN)]`, rejecting codes that aren't client or server errors.
+fn status_attr(attrs: &[Attribute]) -> syn::Result<Option<u16>> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("status")) else {
+        return Ok(None);
+    };
+
+    let literal: LitInt = attr.parse_args()?;
+    let status: u16 = literal.base10_parse()?;
+    if !(400..=599).contains(&status) {
+        return Err(Error::new(
+            literal.span(),
+            "status must be a client or server error (400-599)",
+        ));
+    }
+    Ok(Some(status))
+}
@@ -0,0 +1,170 @@
+use axum::http::HeaderValue;
+use std::fmt;
+use std::time::Duration;
+
+/// `SameSite` attribute of a cookie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+impl SameSite {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SameSite::Strict => "Strict",
+            SameSite::Lax => "Lax",
+            SameSite::None => "None",
+        }
+    }
+}
+
+/// Builder for `Set-Cookie` header values.
+///
+/// Attributes are rendered in a fixed order: `Max-Age`, `Path`, `Secure`, `HttpOnly`,
+/// `SameSite`. The value is percent-encoded where it contains characters that are not allowed
+/// in a cookie value (whitespace, `"`, `,`, `;`, `\`, control and non-ASCII characters), so
+/// arbitrary strings can't break the header.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use skyak_axum_core::cookies::{CookieBuilder, SameSite};
+///
+/// let cookie = CookieBuilder::new("session", "abc123")
+///     .path("/")
+///     .http_only()
+///     .secure()
+///     .same_site(SameSite::Lax)
+///     .max_age(Duration::from_secs(3600));
+///
+/// assert_eq!(
+///     cookie.header_value().unwrap(),
+///     "session=abc123; Max-Age=3600; Path=/; Secure; HttpOnly; SameSite=Lax"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct CookieBuilder {
+    name: String,
+    value: String,
+    http_only: bool,
+    secure: bool,
+    same_site: Option<SameSite>,
+    max_age: Option<Duration>,
+    path: Option<String>,
+}
+
+impl CookieBuilder {
+    /// Creates a cookie with the given name and value and no attributes.
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        CookieBuilder {
+            name: name.into(),
+            value: value.into(),
+            http_only: false,
+            secure: false,
+            same_site: None,
+            max_age: None,
+            path: None,
+        }
+    }
+
+    /// Hides the cookie from client-side scripts.
+    pub fn http_only(mut self) -> Self {
+        self.http_only = true;
+        self
+    }
+
+    /// Only sends the cookie over HTTPS.
+    pub fn secure(mut self) -> Self {
+        self.secure = true;
+        self
+    }
+
+    /// Sets the `SameSite` attribute.
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = Some(same_site);
+        self
+    }
+
+    /// Sets `Max-Age`, in whole seconds.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Restricts the cookie to the given path.
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Renders the cookie as a `Set-Cookie` header value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidCookie`] when the name is not a valid token or the path contains
+    /// `;` or control characters.
+    pub fn header_value(&self) -> Result<HeaderValue, InvalidCookie> {
+        if self.name.is_empty() || !self.name.bytes().all(is_token_byte) {
+            return Err(InvalidCookie("cookie name must be a non-empty token"));
+        }
+
+        let mut cookie = format!("{}={}", self.name, encode_value(&self.value));
+        if let Some(max_age) = self.max_age {
+            cookie.push_str(&format!("; Max-Age={}", max_age.as_secs()));
+        }
+        if let Some(path) = &self.path {
+            if path
+                .bytes()
+                .any(|byte| byte == b';' || byte.is_ascii_control())
+            {
+                return Err(InvalidCookie("cookie path contains invalid characters"));
+            }
+            cookie.push_str(&format!("; Path={path}"));
+        }
+        if self.secure {
+            cookie.push_str("; Secure");
+        }
+        if self.http_only {
+            cookie.push_str("; HttpOnly");
+        }
+        if let Some(same_site) = self.same_site {
+            cookie.push_str(&format!("; SameSite={}", same_site.as_str()));
+        }
+
+        HeaderValue::from_str(&cookie).map_err(|_| InvalidCookie("cookie is not a valid header"))
+    }
+}
+
+/// Error returned by [`CookieBuilder::header_value`] for cookies that can't be rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCookie(&'static str);
+
+impl fmt::Display for InvalidCookie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid cookie: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidCookie {}
+
+/// RFC 7230 `tchar`.
+fn is_token_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte)
+}
+
+/// Percent-encodes every byte outside the RFC 6265 `cookie-octet` set, plus `%` itself so the
+/// encoding is unambiguous.
+fn encode_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            0x21 | 0x23..=0x24 | 0x26..=0x2B | 0x2D..=0x3A | 0x3C..=0x5B | 0x5D..=0x7E => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
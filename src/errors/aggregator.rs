@@ -0,0 +1,55 @@
+use super::ApiError;
+
+/// Collects independent errors, e.g. while validating a request, into one `Multiple` error.
+///
+/// Identical errors are reported once: when the same field fails two rules with the same
+/// message, the `details` array lists it a single time. First occurrences keep their order.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::errors::{ApiError, ErrorAggregator};
+///
+/// let mut errors = ErrorAggregator::new();
+/// errors.push(ApiError::bad_request("Name is required"));
+/// errors.push(ApiError::bad_request("Name is required"));
+///
+/// let error = errors.build().unwrap();
+/// assert_eq!(error, ApiError::multiple([ApiError::bad_request("Name is required")]));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ErrorAggregator {
+    errors: Vec<ApiError>,
+}
+
+impl ErrorAggregator {
+    /// Creates an empty aggregator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error.
+    pub fn push(&mut self, error: ApiError) {
+        self.errors.push(error);
+    }
+
+    /// Whether no error was pushed.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Builds a `Multiple` error from the distinct errors pushed, or `None` when there are
+    /// none.
+    pub fn build(self) -> Option<ApiError> {
+        if self.errors.is_empty() {
+            return None;
+        }
+        let mut distinct: Vec<ApiError> = Vec::with_capacity(self.errors.len());
+        for error in self.errors {
+            if !distinct.contains(&error) {
+                distinct.push(error);
+            }
+        }
+        Some(ApiError::multiple(distinct))
+    }
+}
@@ -0,0 +1,217 @@
+use super::ApiErrorBody;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::RwLock;
+
+/// Field names used for the JSON error body rendered in responses.
+///
+/// Installed process-wide with [`configure`], typically once at startup, for frontends that
+/// expect e.g. `{"code": "...", "msg": "..."}`. Only the rendered responses are affected;
+/// [`ApiErrorBody`] itself always (de)serializes with the default `error` and `message` keys.
+/// [`ApiError::from_response`](super::ApiError::from_response) and `testing::assert_error`
+/// read responses with the configured keys.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::errors::{self, ApiErrorConfig};
+///
+/// errors::configure(ApiErrorConfig::new().code_key("code").message_key("msg")).unwrap();
+///
+/// // Keys must not collide with each other or with the other fields of the body.
+/// assert!(errors::configure(ApiErrorConfig::new().message_key("status")).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiErrorConfig {
+    /// Key of the machine-readable [`code`](super::ApiError::code), `error` by default.
+    pub code_key: Cow<'static, str>,
+    /// Key of the human-readable message, `message` by default.
+    pub message_key: Cow<'static, str>,
+}
+
+impl ApiErrorConfig {
+    /// Configuration with the default `error` and `message` keys.
+    pub const fn new() -> Self {
+        ApiErrorConfig {
+            code_key: Cow::Borrowed("error"),
+            message_key: Cow::Borrowed("message"),
+        }
+    }
+
+    /// Sets the key of the error code.
+    pub fn code_key(mut self, key: impl Into<Cow<'static, str>>) -> Self {
+        self.code_key = key.into();
+        self
+    }
+
+    /// Sets the key of the error message.
+    pub fn message_key(mut self, key: impl Into<Cow<'static, str>>) -> Self {
+        self.message_key = key.into();
+        self
+    }
+}
+
+impl Default for ApiErrorConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Keys of the [`ApiErrorBody`] fields that can't be renamed.
+const FIXED_KEYS: [&str; 8] = [
+    "status",
+    "field",
+    "errors",
+    "rate_limit",
+    "details",
+    "request_id",
+    "backtrace",
+    "timestamp",
+];
+
+/// Error returned by [`configure`] when a key would appear twice in rendered bodies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidConfig(pub Cow<'static, str>);
+
+impl fmt::Display for InvalidConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error body key `{}` is already in use", self.0)
+    }
+}
+
+impl std::error::Error for InvalidConfig {}
+
+static CONFIG: RwLock<ApiErrorConfig> = RwLock::new(ApiErrorConfig::new());
+
+/// Installs the field names used for JSON error bodies in responses.
+///
+/// Rejects, and leaves the current configuration in place for, keys that equal each other
+/// or one of the fixed keys such as `status` or `details`.
+pub fn configure(config: ApiErrorConfig) -> Result<(), InvalidConfig> {
+    for key in [&config.code_key, &config.message_key] {
+        if FIXED_KEYS.contains(&&**key) {
+            return Err(InvalidConfig(key.clone()));
+        }
+    }
+    if config.code_key == config.message_key {
+        return Err(InvalidConfig(config.message_key));
+    }
+
+    *CONFIG
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = config;
+    Ok(())
+}
+
+/// The currently installed configuration.
+pub(crate) fn current() -> ApiErrorConfig {
+    CONFIG
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// Parses a JSON error body rendered with the configured keys.
+pub(crate) fn parse_body(bytes: &[u8]) -> serde_json::Result<ApiErrorBody> {
+    let mut json: Value = serde_json::from_slice(bytes)?;
+    restore_default_keys(&mut json, &current());
+    serde_json::from_value(json)
+}
+
+/// Renames the configured keys of a body and its `details` back to `error` and `message`.
+fn restore_default_keys(json: &mut Value, config: &ApiErrorConfig) {
+    let Some(fields) = json.as_object_mut() else {
+        return;
+    };
+    let code = fields.remove(&*config.code_key);
+    let message = fields.remove(&*config.message_key);
+    if let Some(code) = code {
+        fields.insert("error".to_string(), code);
+    }
+    if let Some(message) = message {
+        fields.insert("message".to_string(), message);
+    }
+    if let Some(Value::Array(details)) = fields.get_mut("details") {
+        for detail in details {
+            restore_default_keys(detail, config);
+        }
+    }
+}
+
+/// Serializes an [`ApiErrorBody`] with the configured keys, for rendering responses.
+///
+/// Mirrors the field order and skip rules of the derived `Serialize`; a test in
+/// `tests/config_tests.rs` checks that every field is rendered.
+pub(crate) struct ConfiguredBody {
+    pub(crate) body: ApiErrorBody,
+    pub(crate) config: ApiErrorConfig,
+}
+
+impl ConfiguredBody {
+    pub(crate) fn new(body: ApiErrorBody) -> Self {
+        ConfiguredBody {
+            body,
+            config: current(),
+        }
+    }
+}
+
+impl Serialize for ConfiguredBody {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Keyed {
+            body: &self.body,
+            config: &self.config,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Borrowed form of [`ConfiguredBody`], so nested `details` serialize without cloning.
+struct Keyed<'a> {
+    body: &'a ApiErrorBody,
+    config: &'a ApiErrorConfig,
+}
+
+impl Serialize for Keyed<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let body = self.body;
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry(&*self.config.code_key, &body.error)?;
+        map.serialize_entry("status", &body.status)?;
+        if let Some(field) = &body.field {
+            map.serialize_entry("field", field)?;
+        }
+        if let Some(message) = &body.message {
+            map.serialize_entry(&*self.config.message_key, message)?;
+        }
+        if !body.errors.is_empty() {
+            map.serialize_entry("errors", &body.errors)?;
+        }
+        if let Some(rate_limit) = &body.rate_limit {
+            map.serialize_entry("rate_limit", rate_limit)?;
+        }
+        if !body.details.is_empty() {
+            let details: Vec<_> = body
+                .details
+                .iter()
+                .map(|body| Keyed {
+                    body,
+                    config: self.config,
+                })
+                .collect();
+            map.serialize_entry("details", &details)?;
+        }
+        if let Some(request_id) = &body.request_id {
+            map.serialize_entry("request_id", request_id)?;
+        }
+        if let Some(backtrace) = &body.backtrace {
+            map.serialize_entry("backtrace", backtrace)?;
+        }
+        if let Some(timestamp) = &body.timestamp {
+            map.serialize_entry("timestamp", timestamp)?;
+        }
+        map.end()
+    }
+}
@@ -0,0 +1,333 @@
+use super::{ApiError, FieldErrors, InvalidStatus};
+use axum::body::to_bytes;
+use axum::extract::rejection::{FormRejection, JsonRejection, PathRejection, QueryRejection};
+use axum::http::{header, StatusCode};
+use axum::response::Response;
+use std::borrow::Cow;
+use std::io::ErrorKind;
+use std::time::Duration;
+
+/// Converts JSON (de)serialization failures into a `400 Bad Request`.
+///
+/// This lets handlers that parse bodies manually use `?` on `serde_json` results.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::errors::ApiError;
+///
+/// fn parse(raw: &str) -> Result<serde_json::Value, ApiError> {
+///     Ok(serde_json::from_str(raw)?)
+/// }
+///
+/// assert!(matches!(parse("{"), Err(ApiError::BadRequest(Some(_)))));
+/// ```
+impl From<serde_json::Error> for ApiError {
+    fn from(error: serde_json::Error) -> Self {
+        ApiError::BadRequest(Some(format!("invalid JSON: {error}")))
+    }
+}
+
+/// Converts I/O failures into the closest HTTP status, keeping the error text as the message.
+///
+/// `NotFound` becomes `404 Not Found`, `PermissionDenied` `403 Forbidden` and `TimedOut`
+/// `504 Gateway Timeout`; every other kind is a `500 Internal Server Error`.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::errors::ApiError;
+///
+/// fn read_report(path: &str) -> Result<String, ApiError> {
+///     Ok(std::fs::read_to_string(path)?)
+/// }
+///
+/// assert!(matches!(read_report("/does/not/exist"), Err(ApiError::NotFound(Some(_)))));
+/// ```
+impl From<std::io::Error> for ApiError {
+    fn from(error: std::io::Error) -> Self {
+        let message = Some(error.to_string());
+        match error.kind() {
+            ErrorKind::NotFound => ApiError::NotFound(message),
+            ErrorKind::PermissionDenied => ApiError::Forbidden(message),
+            ErrorKind::TimedOut => ApiError::GatewayTimeout(message),
+            _ => ApiError::InternalServerError(message),
+        }
+    }
+}
+
+/// Maps an extractor rejection to the matching variant, keeping axum's description as the
+/// message: `413 Payload Too Large` and `422 Unprocessable Entity` are preserved, everything
+/// else is a `400 Bad Request`.
+fn rejection(status: StatusCode, message: String) -> ApiError {
+    match status {
+        StatusCode::PAYLOAD_TOO_LARGE => ApiError::PayloadTooLarge(Some(message)),
+        StatusCode::UNPROCESSABLE_ENTITY => ApiError::unprocessable_entity(message),
+        _ => ApiError::BadRequest(Some(message)),
+    }
+}
+
+/// Converts a `Json` extractor rejection: malformed JSON and a missing `Content-Type` become
+/// `400 Bad Request`, valid JSON of the wrong shape `422 Unprocessable Entity`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::extract::rejection::JsonRejection;
+/// use axum::Json;
+/// use skyak_axum_core::errors::ApiError;
+///
+/// async fn create(payload: Result<Json<serde_json::Value>, JsonRejection>) -> Result<String, ApiError> {
+///     let Json(payload) = payload?;
+///     Ok(payload.to_string())
+/// }
+/// ```
+impl From<JsonRejection> for ApiError {
+    fn from(error: JsonRejection) -> Self {
+        rejection(error.status(), error.body_text())
+    }
+}
+
+/// Converts a `Query` extractor rejection into a `400 Bad Request`.
+impl From<QueryRejection> for ApiError {
+    fn from(error: QueryRejection) -> Self {
+        rejection(error.status(), error.body_text())
+    }
+}
+
+/// Converts a `Path` extractor rejection into a `400 Bad Request`.
+impl From<PathRejection> for ApiError {
+    fn from(error: PathRejection) -> Self {
+        ApiError::BadRequest(Some(error.body_text()))
+    }
+}
+
+/// Converts a `Form` extractor rejection: a form body that doesn't match the target type
+/// becomes `422 Unprocessable Entity`, anything else `400 Bad Request`.
+impl From<FormRejection> for ApiError {
+    fn from(error: FormRejection) -> Self {
+        rejection(error.status(), error.body_text())
+    }
+}
+
+/// Maps an error status to its dedicated variant without a message, e.g. when proxying an
+/// upstream response; statuses without one become `Other`. Non-error statuses (1xx-3xx) are
+/// rejected with [`InvalidStatus`].
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::StatusCode;
+/// use skyak_axum_core::errors::{ApiError, InvalidStatus};
+///
+/// assert_eq!(ApiError::try_from(StatusCode::NOT_FOUND), Ok(ApiError::NotFound(None)));
+/// assert_eq!(ApiError::try_from(StatusCode::OK), Err(InvalidStatus(200)));
+/// ```
+impl TryFrom<StatusCode> for ApiError {
+    type Error = InvalidStatus;
+
+    fn try_from(status: StatusCode) -> Result<Self, Self::Error> {
+        if status.is_client_error() || status.is_server_error() {
+            Ok(for_status(status, None))
+        } else {
+            Err(InvalidStatus(status.as_u16()))
+        }
+    }
+}
+
+/// Variant for an HTTP status, carrying `message`; statuses without a dedicated variant
+/// become `Other`.
+pub(crate) fn for_status(status: StatusCode, message: Option<String>) -> ApiError {
+    match status {
+        StatusCode::BAD_REQUEST => ApiError::BadRequest(message),
+        StatusCode::NOT_FOUND => ApiError::NotFound(message),
+        StatusCode::INTERNAL_SERVER_ERROR => ApiError::InternalServerError(message),
+        StatusCode::UNAUTHORIZED => ApiError::Unauthorized {
+            message,
+            challenge: None,
+        },
+        StatusCode::FORBIDDEN => ApiError::Forbidden(message),
+        StatusCode::CONFLICT => ApiError::Conflict {
+            message,
+            conflicting_field: None,
+        },
+        StatusCode::METHOD_NOT_ALLOWED => ApiError::MethodNotAllowed(message),
+        StatusCode::NOT_ACCEPTABLE => ApiError::NotAcceptable(message),
+        StatusCode::GONE => ApiError::Gone(message),
+        StatusCode::PAYLOAD_TOO_LARGE => ApiError::PayloadTooLarge(message),
+        StatusCode::UNPROCESSABLE_ENTITY => ApiError::UnprocessableEntity {
+            message,
+            errors: Vec::new(),
+        },
+        StatusCode::TOO_MANY_REQUESTS => ApiError::TooManyRequests {
+            message,
+            retry_after: None,
+            rate_limit: None,
+        },
+        StatusCode::NOT_IMPLEMENTED => ApiError::NotImplemented(message),
+        StatusCode::BAD_GATEWAY => ApiError::BadGateway(message),
+        StatusCode::SERVICE_UNAVAILABLE => ApiError::ServiceUnavailable {
+            message,
+            retry_after: None,
+        },
+        StatusCode::GATEWAY_TIMEOUT => ApiError::GatewayTimeout(message),
+        _ => ApiError::Other(status.as_u16(), message),
+    }
+}
+
+impl ApiError {
+    /// Reconstructs an error from a parsed JSON body, the inverse of [`ApiError::to_body`].
+    ///
+    /// The variant follows `status`; a `message` equal to the variant's default is treated as
+    /// no custom message, so `NotFound(None)` round-trips unchanged. Field errors, the
+    /// conflicting field, rate limit state, aggregated `details` and the request ID are
+    /// restored as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum::http::StatusCode;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// let error = ApiError::not_found("No such user");
+    /// let body = serde_json::from_str(&serde_json::to_string(&error.to_body()).unwrap()).unwrap();
+    /// assert_eq!(ApiError::from_body(StatusCode::NOT_FOUND, body), error);
+    /// ```
+    pub fn from_body(status: StatusCode, body: super::ApiErrorBody) -> Self {
+        let message = body.message.map(Cow::into_owned);
+        let error = if body.details.is_empty() {
+            for_status(status, message)
+        } else {
+            ApiError::Multiple(
+                body.details
+                    .into_iter()
+                    .map(|detail| {
+                        let status = StatusCode::from_u16(detail.status)
+                            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+                        ApiError::from_body(status, detail)
+                    })
+                    .collect(),
+            )
+        };
+
+        let mut error = match error {
+            ApiError::Conflict { message, .. } => ApiError::Conflict {
+                message,
+                conflicting_field: body.field,
+            },
+            ApiError::UnprocessableEntity { message, .. } => match body.errors {
+                FieldErrors::Fields(errors) => ApiError::UnprocessableEntity {
+                    message,
+                    errors: errors.into_iter().collect(),
+                },
+                FieldErrors::Pointers(errors) => ApiError::Validation {
+                    message,
+                    errors: errors
+                        .into_iter()
+                        .map(|error| (error.pointer, error.message))
+                        .collect(),
+                },
+            },
+            ApiError::TooManyRequests {
+                message,
+                retry_after,
+                ..
+            } => ApiError::TooManyRequests {
+                message,
+                retry_after,
+                rate_limit: body.rate_limit,
+            },
+            other => other,
+        };
+        error.clear_default_message();
+        match body.request_id {
+            Some(request_id) => error.with_request_id(request_id),
+            None => error,
+        }
+    }
+
+    /// Reads an error response, e.g. one received by a Rust client of the API, back into an
+    /// `ApiError`.
+    ///
+    /// The variant follows the HTTP status and the rest is restored from the JSON body as in
+    /// [`ApiError::from_body`], plus a `Retry-After` given in seconds and the
+    /// `WWW-Authenticate` challenge of an `Unauthorized`. The body is read with the
+    /// [configured](super::configure) keys; one that isn't an error body yields the variant
+    /// without a message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum::response::IntoResponse;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// # tokio_test();
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn tokio_test() {
+    /// let response = ApiError::conflict("Email taken").into_response();
+    /// assert_eq!(ApiError::from_response(response).await, ApiError::conflict("Email taken"));
+    /// # }
+    /// ```
+    pub async fn from_response(response: Response) -> Self {
+        let (parts, body) = response.into_parts();
+        let retry_after = parts
+            .headers
+            .get(header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(Duration::from_secs);
+        // The default `Bearer` challenge is rendered for `None`, so it reads back as `None`.
+        let challenge = parts
+            .headers
+            .get(header::WWW_AUTHENTICATE)
+            .and_then(|value| value.to_str().ok())
+            .filter(|challenge| *challenge != "Bearer")
+            .map(str::to_string);
+        let body = to_bytes(body, usize::MAX)
+            .await
+            .ok()
+            .and_then(|bytes| super::parse_body(&bytes).ok());
+
+        let error = match body {
+            Some(body) => ApiError::from_body(parts.status, body),
+            None => for_status(parts.status, None),
+        };
+        let error = match retry_after {
+            Some(retry_after) => error.with_retry_after(retry_after),
+            None => error,
+        };
+        match challenge {
+            Some(challenge) => error.with_challenge(challenge),
+            None => error,
+        }
+    }
+
+    /// Drops a custom message that merely repeats the variant's default.
+    fn clear_default_message(&mut self) {
+        let default = self.default_message();
+        let message = match self {
+            ApiError::BadRequest(message)
+            | ApiError::NotFound(message)
+            | ApiError::InternalServerError(message)
+            | ApiError::Unauthorized { message, .. }
+            | ApiError::Forbidden(message)
+            | ApiError::Conflict { message, .. }
+            | ApiError::MethodNotAllowed(message)
+            | ApiError::NotAcceptable(message)
+            | ApiError::Gone(message)
+            | ApiError::PayloadTooLarge(message)
+            | ApiError::UnprocessableEntity { message, .. }
+            | ApiError::TooManyRequests { message, .. }
+            | ApiError::Validation { message, .. }
+            | ApiError::NotImplemented(message)
+            | ApiError::BadGateway(message)
+            | ApiError::ServiceUnavailable { message, .. }
+            | ApiError::GatewayTimeout(message)
+            | ApiError::Other(_, message) => message,
+            ApiError::Multiple(_) | ApiError::WithContext(_, _) => return,
+        };
+        if message.as_deref() == Some(default) {
+            *message = None;
+        }
+    }
+}
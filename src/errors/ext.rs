@@ -0,0 +1,90 @@
+use super::ApiError;
+use std::fmt::Display;
+
+/// Converts any `Result` into a `Result<T, ApiError>`, using the error's `Display` output as
+/// the message of the chosen variant.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::errors::{ApiError, ResultExt};
+///
+/// fn parse_id(raw: &str) -> Result<u32, ApiError> {
+///     raw.parse::<u32>().bad_request()
+/// }
+///
+/// assert!(matches!(parse_id("abc"), Err(ApiError::BadRequest(Some(_)))));
+/// ```
+pub trait ResultExt<T> {
+    /// Maps the error to `400 Bad Request`.
+    fn bad_request(self) -> Result<T, ApiError>;
+
+    /// Maps the error to `403 Forbidden`.
+    fn forbidden(self) -> Result<T, ApiError>;
+
+    /// Maps the error to `404 Not Found`.
+    fn not_found(self) -> Result<T, ApiError>;
+
+    /// Maps the error to `409 Conflict`.
+    fn conflict(self) -> Result<T, ApiError>;
+
+    /// Maps the error to `500 Internal Server Error`.
+    fn internal(self) -> Result<T, ApiError>;
+}
+
+impl<T, E: Display> ResultExt<T> for Result<T, E> {
+    fn bad_request(self) -> Result<T, ApiError> {
+        self.map_err(|error| ApiError::bad_request(error.to_string()))
+    }
+
+    fn forbidden(self) -> Result<T, ApiError> {
+        self.map_err(|error| ApiError::forbidden(error.to_string()))
+    }
+
+    fn not_found(self) -> Result<T, ApiError> {
+        self.map_err(|error| ApiError::not_found(error.to_string()))
+    }
+
+    fn conflict(self) -> Result<T, ApiError> {
+        self.map_err(|error| ApiError::conflict(error.to_string()))
+    }
+
+    fn internal(self) -> Result<T, ApiError> {
+        self.map_err(|error| ApiError::internal_server_error(error.to_string()))
+    }
+}
+
+/// Converts a missing value into a `404 Not Found`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use skyak_axum_core::errors::{ApiError, OptionExt};
+///
+/// fn find_user(users: &HashMap<u32, String>, id: u32) -> Result<&String, ApiError> {
+///     users.get(&id).or_not_found_msg("User not found")
+/// }
+///
+/// assert_eq!(
+///     find_user(&HashMap::new(), 7),
+///     Err(ApiError::not_found("User not found"))
+/// );
+/// ```
+pub trait OptionExt<T> {
+    /// Maps `None` to `ApiError::NotFound(None)`.
+    fn or_not_found(self) -> Result<T, ApiError>;
+
+    /// Maps `None` to a `NotFound` with the given message.
+    fn or_not_found_msg(self, message: impl Into<String>) -> Result<T, ApiError>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn or_not_found(self) -> Result<T, ApiError> {
+        self.ok_or(ApiError::NotFound(None))
+    }
+
+    fn or_not_found_msg(self, message: impl Into<String>) -> Result<T, ApiError> {
+        self.ok_or_else(|| ApiError::not_found(message))
+    }
+}
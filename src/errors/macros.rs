@@ -0,0 +1,124 @@
+/// Returns early with an [`ApiError`](crate::errors::ApiError) of the given variant.
+///
+/// The variant is named without the `ApiError::` prefix and followed by an optional
+/// `format!`-style message; `Other` takes its status code in parentheses. The error is
+/// converted with `From`, so it also works in functions returning a domain error type.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::api_bail;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::{success, ApiResponse};
+///
+/// fn find_user(id: u32) -> ApiResponse<String> {
+///     if id == 0 {
+///         api_bail!(NotFound, "User {} not found", id);
+///     }
+///     if id == 418 {
+///         api_bail!(Other(418));
+///     }
+///     success(format!("user {id}"))
+/// }
+///
+/// assert_eq!(
+///     find_user(0).unwrap_err(),
+///     ApiError::not_found("User 0 not found")
+/// );
+/// ```
+#[macro_export]
+macro_rules! api_bail {
+    ($($variant:tt)+) => {
+        return ::core::result::Result::Err(::core::convert::From::from(
+            $crate::__api_error!($($variant)+),
+        ))
+    };
+}
+
+/// Returns early with an [`ApiError`](crate::errors::ApiError) unless a condition holds.
+///
+/// `api_ensure!(cond, Variant, "message {}", arg)` is shorthand for
+/// `if !cond { api_bail!(Variant, "message {}", arg) }`.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::api_ensure;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::{success, ApiResponse};
+///
+/// fn rename(name: &str) -> ApiResponse<String> {
+///     api_ensure!(!name.is_empty(), BadRequest, "Name must not be empty");
+///     api_ensure!(name.len() <= 32, UnprocessableEntity, "Name is {} characters", name.len());
+///     success(name.to_string())
+/// }
+///
+/// assert_eq!(rename("").unwrap_err(), ApiError::bad_request("Name must not be empty"));
+/// ```
+#[macro_export]
+macro_rules! api_ensure {
+    ($condition:expr, $($variant:tt)+) => {
+        if !$condition {
+            $crate::api_bail!($($variant)+);
+        }
+    };
+}
+
+/// Builds the `ApiError` for [`api_bail!`]: a variant name, optionally followed by a
+/// message format string and its arguments.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __api_error {
+    (Other($status:expr) $(, $($message:tt)+)?) => {
+        $crate::errors::ApiError::Other($status, $crate::__api_error!(@message $($($message)+)?))
+    };
+    ($variant:ident $(, $($message:tt)+)?) => {
+        $crate::__api_error!(@variant $variant, $crate::__api_error!(@message $($($message)+)?))
+    };
+    (@message) => {
+        ::core::option::Option::None
+    };
+    (@message $($message:tt)+) => {
+        ::core::option::Option::Some(::std::format!($($message)+))
+    };
+    (@variant Unauthorized, $message:expr) => {
+        $crate::errors::ApiError::Unauthorized {
+            message: $message,
+            challenge: ::core::option::Option::None,
+        }
+    };
+    (@variant Conflict, $message:expr) => {
+        $crate::errors::ApiError::Conflict {
+            message: $message,
+            conflicting_field: ::core::option::Option::None,
+        }
+    };
+    (@variant UnprocessableEntity, $message:expr) => {
+        $crate::errors::ApiError::UnprocessableEntity {
+            message: $message,
+            errors: ::std::vec::Vec::new(),
+        }
+    };
+    (@variant Validation, $message:expr) => {
+        $crate::errors::ApiError::Validation {
+            message: $message,
+            errors: ::std::vec::Vec::new(),
+        }
+    };
+    (@variant TooManyRequests, $message:expr) => {
+        $crate::errors::ApiError::TooManyRequests {
+            message: $message,
+            retry_after: ::core::option::Option::None,
+            rate_limit: ::core::option::Option::None,
+        }
+    };
+    (@variant ServiceUnavailable, $message:expr) => {
+        $crate::errors::ApiError::ServiceUnavailable {
+            message: $message,
+            retry_after: ::core::option::Option::None,
+        }
+    };
+    (@variant $variant:ident, $message:expr) => {
+        $crate::errors::ApiError::$variant($message)
+    };
+}
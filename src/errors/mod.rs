@@ -1,13 +1,45 @@
-use axum::http::StatusCode;
-use axum::response::{IntoResponse, Response};
-use serde::Serialize;
+use crate::headers::accept_quality;
+use crate::locale::{self, Lang};
+use axum::body::Bytes;
+use axum::http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode};
+use axum::response::{Html, IntoResponse, Response};
+use http_body_util::Full;
+use serde::{Deserialize, Serialize};
+use std::backtrace::Backtrace;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+mod aggregator;
+mod config;
+mod conversions;
+mod ext;
+mod macros;
+#[cfg(feature = "timestamps")]
+mod timestamp;
+
+pub use aggregator::ErrorAggregator;
+use config::ConfiguredBody;
+pub use config::{configure, ApiErrorConfig, InvalidConfig};
+pub(crate) use config::{current as current_config, parse_body};
+pub(crate) use conversions::for_status;
+pub use ext::{OptionExt, ResultExt};
+
+#[cfg(feature = "derive")]
+pub use skyak_axum_core_derive::IntoApiError;
 
 /// Represents common HTTP API errors with optional custom messages.
 ///
 /// This enum provides a standardized way to handle HTTP errors in an Axum web application.
 /// Each variant corresponds to a specific HTTP status code and can optionally include
-/// a custom error message. When converted to a response, it will use either the provided
-/// custom message or a default message appropriate for the error type.
+/// a custom error message. When converted to a response, it renders a JSON body of the form
+/// `{"error": "not_found", "status": 404, "message": "User profile not found"}`, where `error` is
+/// the stable machine-readable [`ApiError::code`], `status` the numeric HTTP status, and
+/// `message` the provided custom message. Without a custom message, `message` is omitted and
+/// the `error` code alone identifies the error; see [`ApiError::default_message`].
 ///
 /// # Examples
 ///
@@ -17,70 +49,1575 @@ use serde::Serialize;
 /// // With custom error message
 /// let not_found = ApiError::NotFound(Some("User profile not found".to_string()));
 ///
-/// // Without custom message (will use default)
-/// let unauthorized = ApiError::Unauthorized(None);
+/// // The same, using the constructor
+/// let not_found = ApiError::not_found("User profile not found");
+///
+/// // Without custom message (the body omits `message`)
+/// let unauthorized = ApiError::Unauthorized {
+///     message: None,
+///     challenge: None,
+/// };
+///
+/// // Validation failure with per-field errors
+/// let invalid = ApiError::UnprocessableEntity {
+///     message: None,
+///     errors: vec![("email".to_string(), "invalid".to_string())],
+/// };
 ///
 /// // Custom status code
 /// let teapot = ApiError::Other(418, Some("I'm a teapot".to_string()));
+///
+/// // Errors compare by value, which keeps test assertions short
+/// assert_eq!(ApiError::not_found("User profile not found"), not_found);
 /// ```
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
 pub enum ApiError {
     BadRequest(Option<String>),
     NotFound(Option<String>),
     InternalServerError(Option<String>),
-    Unauthorized(Option<String>),
+    /// Missing or invalid credentials; `challenge` is emitted as the `WWW-Authenticate`
+    /// header, defaulting to `Bearer` when unset.
+    Unauthorized {
+        message: Option<String>,
+        challenge: Option<String>,
+    },
     Forbidden(Option<String>),
-    Conflict(Option<String>),
-    TooManyRequests(Option<String>),
-    ServiceUnavailable(Option<String>),
+    /// Conflict with the current state of a resource; `conflicting_field` names the input
+    /// that conflicted (e.g. a unique `email`) and is rendered as `field` in the body.
+    Conflict {
+        message: Option<String>,
+        conflicting_field: Option<String>,
+    },
+    MethodNotAllowed(Option<String>),
+    NotAcceptable(Option<String>),
+    Gone(Option<String>),
+    PayloadTooLarge(Option<String>),
+    /// Validation failure, optionally carrying field name → error message pairs that are
+    /// rendered as an `errors` object in the response body.
+    UnprocessableEntity {
+        message: Option<String>,
+        errors: Vec<(String, String)>,
+    },
+    /// Rate limit exceeded; `retry_after` is emitted as a `Retry-After` header and
+    /// `rate_limit` as the `X-RateLimit-*` headers and a `rate_limit` body field when set.
+    TooManyRequests {
+        message: Option<String>,
+        retry_after: Option<Duration>,
+        rate_limit: Option<RateLimit>,
+    },
+    /// Validation failure of a nested body, carrying JSON Pointer → error message pairs that
+    /// are rendered as an `errors` array of `{"pointer", "message"}` objects. Built by
+    /// [`ApiError::validation`].
+    Validation {
+        message: Option<String>,
+        errors: Vec<(String, String)>,
+    },
+    NotImplemented(Option<String>),
+    BadGateway(Option<String>),
+    /// Temporary outage; `retry_after` is emitted as a `Retry-After` header when set.
+    ServiceUnavailable {
+        message: Option<String>,
+        retry_after: Option<Duration>,
+    },
     GatewayTimeout(Option<String>),
+    /// Any other status code. Codes outside 400-599 fall back to `500 Internal Server Error`
+    /// when rendered and classified; prefer [`ApiError::other`], which validates the code up
+    /// front.
     Other(u16, Option<String>),
+    /// Several independent errors reported in one response, built by [`ApiError::multiple`].
+    /// Renders with the highest status among them and their bodies as a `details` array.
+    Multiple(Vec<ApiError>),
+    /// Any of the other variants, decorated with out-of-band [`ErrorContext`] such as a
+    /// request ID or source error. Built by [`ApiError::with_request_id`] and
+    /// [`ApiError::with_source`] rather than directly; it renders
+    /// exactly like the wrapped error plus the context, and [`ApiError::inner`] exposes the
+    /// wrapped error for pattern matching.
+    WithContext(Box<ApiError>, ErrorContext),
 }
 
-impl IntoResponse for ApiError {
-    fn into_response(self) -> Response {
-        let (status, body) = match self {
-            ApiError::BadRequest(body) => (
-                StatusCode::BAD_REQUEST,
-                body.unwrap_or("Bad Request".to_string()),
-            ),
-            ApiError::NotFound(body) => (
-                StatusCode::NOT_FOUND,
-                body.unwrap_or("Not Found".to_string()),
-            ),
-            ApiError::InternalServerError(body) => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                body.unwrap_or("Internal Server Error".to_string()),
-            ),
-            ApiError::Unauthorized(body) => (
-                StatusCode::UNAUTHORIZED,
-                body.unwrap_or("Unauthorized".to_string()),
-            ),
-            ApiError::Forbidden(body) => (
-                StatusCode::FORBIDDEN,
-                body.unwrap_or("Forbidden".to_string()),
-            ),
-            ApiError::Conflict(body) => {
-                (StatusCode::CONFLICT, body.unwrap_or("Conflict".to_string()))
+/// Out-of-band context attached to an [`ApiError`].
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ErrorContext {
+    /// Request or correlation ID, echoed as `request_id` in the body and as the
+    /// `X-Request-Id` header.
+    pub request_id: Option<String>,
+    /// Underlying cause, returned by `Error::source` for root-cause logging. Never rendered
+    /// into the response body. Shared, so cloning an error doesn't require a clonable cause.
+    #[serde(skip)]
+    pub source: Option<Arc<dyn std::error::Error + Send + Sync>>,
+    /// Backtrace captured by [`ApiError::internal_server_error_with_backtrace`]. Rendered as
+    /// `backtrace` in the body only with the `dev-backtrace` feature.
+    #[serde(skip)]
+    pub backtrace: Option<Arc<Backtrace>>,
+}
+
+/// Contexts compare by request ID only; source errors and backtraces aren't comparable and
+/// are ignored.
+impl PartialEq for ErrorContext {
+    fn eq(&self, other: &Self) -> bool {
+        self.request_id == other.request_id
+    }
+}
+
+impl Eq for ErrorContext {}
+
+/// Rate limit state reported by a `TooManyRequests` error.
+///
+/// Rendered as the `X-RateLimit-Limit`, `X-RateLimit-Remaining` and `X-RateLimit-Reset`
+/// headers and as a `rate_limit` object in the JSON body.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Requests allowed per window.
+    pub limit: u64,
+    /// Requests left in the current window.
+    pub remaining: u64,
+    /// Seconds until the window resets.
+    pub reset: u64,
+}
+
+/// Whole seconds in `duration`, rounding partial seconds up so clients never retry too early.
+fn whole_seconds(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
+
+/// Bridge from domain error types to [`ApiError`].
+///
+/// Implement this for errors modeled in your own crate (e.g. with `thiserror`) to map them to
+/// a standardized response. With the `derive` feature, `#[derive(IntoApiError)]` implements it
+/// together with `IntoResponse`, taking the status from `#[status(NNN)]` attributes and the
+/// message from `Display`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::{IntoResponse, Response};
+/// use skyak_axum_core::errors::{ApiError, IntoApiError};
+///
+/// enum AccountError {
+///     Missing(u64),
+///     Locked,
+/// }
+///
+/// impl IntoApiError for AccountError {
+///     fn to_api_error(&self) -> ApiError {
+///         match self {
+///             AccountError::Missing(id) => ApiError::not_found(format!("Account {id} not found")),
+///             AccountError::Locked => ApiError::forbidden("Account is locked"),
+///         }
+///     }
+/// }
+///
+/// impl IntoResponse for AccountError {
+///     fn into_response(self) -> Response {
+///         self.to_api_response()
+///     }
+/// }
+/// ```
+pub trait IntoApiError {
+    /// Maps the domain error to the `ApiError` it should be rendered as.
+    fn to_api_error(&self) -> ApiError;
+
+    /// Renders the mapped `ApiError` as a response.
+    fn to_api_response(&self) -> Response {
+        self.to_api_error().into_response()
+    }
+}
+
+/// Error returned by [`ApiError::other`] when the status code is not a valid HTTP error status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidStatus(pub u16);
+
+impl fmt::Display for InvalidStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not a valid HTTP error status (400-599)", self.0)
+    }
+}
+
+impl std::error::Error for InvalidStatus {}
+
+impl ApiError {
+    // Constructors accept anything convertible into a `String`, so `&str` and `String` can be
+    // passed without `.to_string()`. Variants stay public for pattern matching.
+
+    /// Creates a `BadRequest` error with a custom message.
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        ApiError::BadRequest(Some(message.into()))
+    }
+
+    /// Creates a `NotFound` error with a custom message.
+    pub fn not_found(message: impl Into<String>) -> Self {
+        ApiError::NotFound(Some(message.into()))
+    }
+
+    /// Creates a `InternalServerError` error with a custom message.
+    pub fn internal_server_error(message: impl Into<String>) -> Self {
+        ApiError::InternalServerError(Some(message.into()))
+    }
+
+    /// Creates an `InternalServerError` with a custom message and, with the `dev-backtrace`
+    /// feature, a backtrace of the call site.
+    ///
+    /// The backtrace is rendered as a `backtrace` string in the JSON body to speed up
+    /// debugging during development, unless [`hide_server_error_messages`] is enabled. Without
+    /// the feature nothing is captured, so production builds never expose it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// let error = ApiError::internal_server_error_with_backtrace("Cache corrupted");
+    /// assert!(matches!(error.inner(), ApiError::InternalServerError(Some(_))));
+    /// ```
+    pub fn internal_server_error_with_backtrace(message: impl Into<String>) -> Self {
+        ApiError::internal_server_error(message).capture_backtrace()
+    }
+
+    #[cfg(feature = "dev-backtrace")]
+    fn capture_backtrace(self) -> Self {
+        let backtrace = Arc::new(Backtrace::force_capture());
+        self.map_context(|context| context.backtrace = Some(backtrace))
+    }
+
+    #[cfg(not(feature = "dev-backtrace"))]
+    fn capture_backtrace(self) -> Self {
+        self
+    }
+
+    /// Creates a `Forbidden` error with a custom message.
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        ApiError::Forbidden(Some(message.into()))
+    }
+
+    /// Creates a `Conflict` error with a custom message and no conflicting field.
+    ///
+    /// Chain [`ApiError::with_conflicting_field`] to name the input that conflicted.
+    pub fn conflict(message: impl Into<String>) -> Self {
+        ApiError::Conflict {
+            message: Some(message.into()),
+            conflicting_field: None,
+        }
+    }
+
+    /// Names the field that caused a `Conflict`, rendered as `field` in the JSON body.
+    ///
+    /// Other variants are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// let error = ApiError::conflict("Email already registered").with_conflicting_field("email");
+    /// ```
+    pub fn with_conflicting_field(self, field: impl Into<String>) -> Self {
+        match self {
+            ApiError::Conflict { message, .. } => ApiError::Conflict {
+                message,
+                conflicting_field: Some(field.into()),
+            },
+            ApiError::WithContext(inner, context) => {
+                ApiError::WithContext(Box::new(inner.with_conflicting_field(field)), context)
             }
-            ApiError::TooManyRequests(body) => (
-                StatusCode::TOO_MANY_REQUESTS,
-                body.unwrap_or("Too Many Requests".to_string()),
-            ),
-            ApiError::ServiceUnavailable(body) => (
-                StatusCode::SERVICE_UNAVAILABLE,
-                body.unwrap_or("Service Unavailable".to_string()),
-            ),
-            ApiError::GatewayTimeout(body) => (
-                StatusCode::GATEWAY_TIMEOUT,
-                body.unwrap_or("Gateway Timeout".to_string()),
-            ),
-            ApiError::Other(status, body) => (
-                StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
-                body.unwrap_or("Other Error".to_string()),
+            other => other,
+        }
+    }
+
+    /// Creates a `MethodNotAllowed` error with a custom message.
+    pub fn method_not_allowed(message: impl Into<String>) -> Self {
+        ApiError::MethodNotAllowed(Some(message.into()))
+    }
+
+    /// Creates a `NotAcceptable` error with a custom message.
+    pub fn not_acceptable(message: impl Into<String>) -> Self {
+        ApiError::NotAcceptable(Some(message.into()))
+    }
+
+    /// Creates a `Gone` error with a custom message.
+    pub fn gone(message: impl Into<String>) -> Self {
+        ApiError::Gone(Some(message.into()))
+    }
+
+    /// Creates a `PayloadTooLarge` error with a custom message.
+    pub fn payload_too_large(message: impl Into<String>) -> Self {
+        ApiError::PayloadTooLarge(Some(message.into()))
+    }
+
+    /// Creates an `UnprocessableEntity` error with a custom message and no field errors.
+    pub fn unprocessable_entity(message: impl Into<String>) -> Self {
+        ApiError::UnprocessableEntity {
+            message: Some(message.into()),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Creates a `Validation` error from JSON Pointer → message pairs.
+    ///
+    /// Keys are JSON Pointers (RFC 6901) into the request body, such as `/items/0/price`, so
+    /// clients can map each message to the exact form field. Renders as `422 Unprocessable
+    /// Entity` with the pairs as an `errors` array, in the given order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum::http::StatusCode;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// let error = ApiError::validation(vec![(
+    ///     "/items/0/price".to_string(),
+    ///     "must be positive".to_string(),
+    /// )]);
+    /// assert_eq!(error.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    /// ```
+    pub fn validation(errors: Vec<(String, String)>) -> Self {
+        ApiError::Validation {
+            message: None,
+            errors,
+        }
+    }
+
+    /// Creates a `NotImplemented` error with a custom message.
+    pub fn not_implemented(message: impl Into<String>) -> Self {
+        ApiError::NotImplemented(Some(message.into()))
+    }
+
+    /// Creates a `BadGateway` error with a custom message.
+    pub fn bad_gateway(message: impl Into<String>) -> Self {
+        ApiError::BadGateway(Some(message.into()))
+    }
+
+    /// Creates a `GatewayTimeout` error with a custom message.
+    pub fn gateway_timeout(message: impl Into<String>) -> Self {
+        ApiError::GatewayTimeout(Some(message.into()))
+    }
+
+    /// Creates an `Other` error after validating the status code.
+    ///
+    /// This is the preferred way to construct `Other`: the status must be a client (4xx) or
+    /// server (5xx) error status, otherwise [`InvalidStatus`] is returned instead of the error
+    /// silently becoming a 500 when rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skyak_axum_core::errors::{ApiError, InvalidStatus};
+    ///
+    /// assert!(ApiError::other(418, Some("I'm a teapot".to_string())).is_ok());
+    /// assert_eq!(ApiError::other(999, None).unwrap_err(), InvalidStatus(999));
+    /// ```
+    pub fn other(status: u16, message: Option<String>) -> Result<Self, InvalidStatus> {
+        match StatusCode::from_u16(status) {
+            Ok(code) if code.is_client_error() || code.is_server_error() => {
+                Ok(ApiError::Other(status, message))
+            }
+            _ => Err(InvalidStatus(status)),
+        }
+    }
+
+    /// Creates an `Other` error, falling back to `default_status` when the status code is not
+    /// a valid HTTP error status.
+    ///
+    /// Makes the fallback explicit where the built-in `500 Internal Server Error` would be
+    /// misleading, e.g. a proxy relaying an upstream's bogus status as `502 Bad Gateway`. The
+    /// fallback maps to its dedicated variant when there is one, and is itself replaced by 500
+    /// if it isn't an error status either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum::http::StatusCode;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// let error = ApiError::other_or(42, StatusCode::BAD_GATEWAY, None);
+    /// assert_eq!(error, ApiError::BadGateway(None));
+    /// assert_eq!(ApiError::other_or(418, StatusCode::BAD_GATEWAY, None), ApiError::Other(418, None));
+    /// ```
+    pub fn other_or(status: u16, default_status: StatusCode, message: Option<String>) -> Self {
+        match ApiError::other(status, message.clone()) {
+            Ok(error) => error,
+            Err(_) if default_status.is_client_error() || default_status.is_server_error() => {
+                conversions::for_status(default_status, message)
+            }
+            Err(_) => ApiError::InternalServerError(message),
+        }
+    }
+
+    // Statuses without a dedicated variant get named constructors delegating to `Other`.
+
+    /// Creates an `Other(402)` (Payment Required) error with a custom message.
+    pub fn payment_required(message: impl Into<String>) -> Self {
+        ApiError::Other(402, Some(message.into()))
+    }
+
+    /// Creates an `Other(412)` (Precondition Failed) error with a custom message.
+    pub fn precondition_failed(message: impl Into<String>) -> Self {
+        ApiError::Other(412, Some(message.into()))
+    }
+
+    /// Creates an `Other(415)` (Unsupported Media Type) error with a custom message.
+    pub fn unsupported_media_type(message: impl Into<String>) -> Self {
+        ApiError::Other(415, Some(message.into()))
+    }
+
+    /// Creates an `Other(418)` (I'm a teapot) error with a custom message.
+    pub fn im_a_teapot(message: impl Into<String>) -> Self {
+        ApiError::Other(418, Some(message.into()))
+    }
+
+    /// Creates an `Other(423)` (Locked) error with a custom message.
+    pub fn locked(message: impl Into<String>) -> Self {
+        ApiError::Other(423, Some(message.into()))
+    }
+
+    /// Creates an `Other(451)` (Unavailable For Legal Reasons) error with a custom message.
+    pub fn unavailable_for_legal_reasons(message: impl Into<String>) -> Self {
+        ApiError::Other(451, Some(message.into()))
+    }
+
+    /// Aggregates several independent errors into one `Multiple` error.
+    ///
+    /// The response uses the most severe (highest) status among the errors, and each error's
+    /// body is listed in the `details` array. `errors` is already taken by the per-field map of
+    /// `UnprocessableEntity`, so the array gets its own key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum::http::StatusCode;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// let error = ApiError::multiple([
+    ///     ApiError::bad_request("Quantity must be positive"),
+    ///     ApiError::not_found("Product 42 does not exist"),
+    /// ]);
+    /// ```
+    pub fn multiple(errors: impl IntoIterator<Item = ApiError>) -> Self {
+        ApiError::Multiple(errors.into_iter().collect())
+    }
+
+    /// Creates an `Unauthorized` error with a custom message and the default `Bearer` challenge.
+    ///
+    /// Chain [`ApiError::with_challenge`] to advertise a different authentication scheme.
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        ApiError::Unauthorized {
+            message: Some(message.into()),
+            challenge: None,
+        }
+    }
+
+    /// Sets the `WWW-Authenticate` challenge on `Unauthorized`, e.g. `Bearer realm="api"`.
+    ///
+    /// Other variants are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// let error = ApiError::unauthorized("Token expired").with_challenge(r#"Bearer realm="api""#);
+    /// ```
+    pub fn with_challenge(self, challenge: impl Into<String>) -> Self {
+        match self {
+            ApiError::Unauthorized { message, .. } => ApiError::Unauthorized {
+                message,
+                challenge: Some(challenge.into()),
+            },
+            ApiError::WithContext(inner, context) => {
+                ApiError::WithContext(Box::new(inner.with_challenge(challenge)), context)
+            }
+            other => other,
+        }
+    }
+
+    /// Creates a `TooManyRequests` error with a custom message and no retry information.
+    ///
+    /// Chain [`ApiError::with_retry_after`] to tell clients when to retry.
+    pub fn too_many_requests(message: impl Into<String>) -> Self {
+        ApiError::TooManyRequests {
+            message: Some(message.into()),
+            retry_after: None,
+            rate_limit: None,
+        }
+    }
+
+    /// Creates a `TooManyRequests` error describing the exhausted rate limit.
+    ///
+    /// `reset` is the time until the window resets; it is also used as the `Retry-After`
+    /// duration. Partial seconds are rounded up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// let error = ApiError::rate_limited(100, 0, Duration::from_secs(60));
+    /// ```
+    pub fn rate_limited(limit: u64, remaining: u64, reset: Duration) -> Self {
+        ApiError::TooManyRequests {
+            message: None,
+            retry_after: Some(reset),
+            rate_limit: Some(RateLimit {
+                limit,
+                remaining,
+                reset: whole_seconds(reset),
+            }),
+        }
+    }
+
+    /// Creates a `ServiceUnavailable` error with a custom message and no retry information.
+    ///
+    /// Chain [`ApiError::with_retry_after`] to tell clients when to retry.
+    pub fn service_unavailable(message: impl Into<String>) -> Self {
+        ApiError::ServiceUnavailable {
+            message: Some(message.into()),
+            retry_after: None,
+        }
+    }
+
+    /// Sets the `Retry-After` duration on `TooManyRequests` and `ServiceUnavailable`.
+    ///
+    /// Other variants have no retry semantics and are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// let error = ApiError::too_many_requests("Slow down").with_retry_after(Duration::from_secs(30));
+    /// ```
+    pub fn with_retry_after(self, duration: Duration) -> Self {
+        match self {
+            ApiError::TooManyRequests {
+                message,
+                rate_limit,
+                ..
+            } => ApiError::TooManyRequests {
+                message,
+                retry_after: Some(duration),
+                rate_limit,
+            },
+            ApiError::ServiceUnavailable { message, .. } => ApiError::ServiceUnavailable {
+                message,
+                retry_after: Some(duration),
+            },
+            ApiError::WithContext(inner, context) => {
+                ApiError::WithContext(Box::new(inner.with_retry_after(duration)), context)
+            }
+            other => other,
+        }
+    }
+
+    /// Attaches a request/correlation ID to the error.
+    ///
+    /// The ID is added as `request_id` to the JSON body and sent as the `X-Request-Id`
+    /// response header, so support staff can correlate a client report with server logs.
+    /// Calling it again replaces the ID instead of nesting contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// let error = ApiError::not_found("User not found").with_request_id("req-42");
+    /// assert!(matches!(error.inner(), ApiError::NotFound(_)));
+    /// ```
+    pub fn with_request_id(self, request_id: impl Into<String>) -> Self {
+        self.map_context(|context| context.request_id = Some(request_id.into()))
+    }
+
+    /// Attaches the underlying cause of the error.
+    ///
+    /// The cause is returned by `std::error::Error::source`, keeping the error chain intact
+    /// for structured logging, but it never appears in the response body: clients only see
+    /// the public message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// fn read_config() -> Result<String, ApiError> {
+    ///     std::fs::read_to_string("/missing/config.toml").map_err(|error| {
+    ///         ApiError::internal_server_error("Configuration unavailable").with_source(error)
+    ///     })
+    /// }
+    ///
+    /// assert!(read_config().unwrap_err().source().is_some());
+    /// ```
+    pub fn with_source(self, source: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        let source = Arc::from(source.into());
+        self.map_context(|context| context.source = Some(source))
+    }
+
+    /// Returns the underlying error, looking through any [`ApiError::WithContext`] wrapper.
+    pub fn inner(&self) -> &ApiError {
+        match self {
+            ApiError::WithContext(inner, _) => inner,
+            error => error,
+        }
+    }
+
+    /// Returns the attached context, if any.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            ApiError::WithContext(_, context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Updates the context, wrapping the error first if it has none yet.
+    fn map_context(self, update: impl FnOnce(&mut ErrorContext)) -> Self {
+        let (inner, mut context) = match self {
+            ApiError::WithContext(inner, context) => (inner, context),
+            error => (Box::new(error), ErrorContext::default()),
+        };
+        update(&mut context);
+        ApiError::WithContext(inner, context)
+    }
+
+    /// HTTP status code the error is rendered with.
+    fn status(&self) -> StatusCode {
+        match self {
+            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::InternalServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            ApiError::Unauthorized { .. } => StatusCode::UNAUTHORIZED,
+            ApiError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ApiError::Conflict { .. } => StatusCode::CONFLICT,
+            ApiError::MethodNotAllowed(_) => StatusCode::METHOD_NOT_ALLOWED,
+            ApiError::NotAcceptable(_) => StatusCode::NOT_ACCEPTABLE,
+            ApiError::Gone(_) => StatusCode::GONE,
+            ApiError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            ApiError::UnprocessableEntity { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::TooManyRequests { .. } => StatusCode::TOO_MANY_REQUESTS,
+            ApiError::Validation { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::NotImplemented(_) => StatusCode::NOT_IMPLEMENTED,
+            ApiError::BadGateway(_) => StatusCode::BAD_GATEWAY,
+            ApiError::ServiceUnavailable { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            ApiError::GatewayTimeout(_) => StatusCode::GATEWAY_TIMEOUT,
+            ApiError::Other(status, _) => match StatusCode::from_u16(*status) {
+                Ok(status) if status.is_client_error() || status.is_server_error() => status,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            ApiError::Multiple(errors) => errors
+                .iter()
+                .map(ApiError::status)
+                .max()
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            ApiError::WithContext(inner, _) => inner.status(),
+        }
+    }
+
+    /// HTTP status the error is rendered with.
+    ///
+    /// `Other` with an invalid code reports `500`, matching the response; `Multiple` reports
+    /// the highest status among its errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum::http::StatusCode;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// assert_eq!(ApiError::NotFound(None).status_code(), StatusCode::NOT_FOUND);
+    /// assert_eq!(ApiError::Other(418, None).status_code(), StatusCode::IM_A_TEAPOT);
+    /// ```
+    pub fn status_code(&self) -> StatusCode {
+        self.status()
+    }
+
+    /// Whether the error is a 4xx client error, e.g. to skip retries.
+    pub fn is_client_error(&self) -> bool {
+        self.status().is_client_error()
+    }
+
+    /// Whether the error is a 5xx server error, e.g. to decide whether to alert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// let error = ApiError::bad_gateway("Payment provider unreachable");
+    /// if error.is_server_error() {
+    ///     // page the on-call engineer
+    /// }
+    /// ```
+    pub fn is_server_error(&self) -> bool {
+        self.status().is_server_error()
+    }
+
+    /// Machine-readable error code, rendered in the `error` field of the JSON body.
+    ///
+    /// Codes are stable snake_case identifiers that clients can branch on independently of
+    /// the HTTP status or the human-readable message. `Other` derives its code from the
+    /// status it is rendered with, e.g. `http_418`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// assert_eq!(ApiError::NotFound(None).code(), "not_found");
+    /// assert_eq!(ApiError::Other(418, None).code(), "http_418");
+    /// ```
+    pub fn code(&self) -> Cow<'static, str> {
+        let code = match self {
+            ApiError::BadRequest(_) => "bad_request",
+            ApiError::NotFound(_) => "not_found",
+            ApiError::InternalServerError(_) => "internal_server_error",
+            ApiError::Unauthorized { .. } => "unauthorized",
+            ApiError::Forbidden(_) => "forbidden",
+            ApiError::Conflict { .. } => "conflict",
+            ApiError::MethodNotAllowed(_) => "method_not_allowed",
+            ApiError::NotAcceptable(_) => "not_acceptable",
+            ApiError::Gone(_) => "gone",
+            ApiError::PayloadTooLarge(_) => "payload_too_large",
+            ApiError::UnprocessableEntity { .. } => "unprocessable_entity",
+            ApiError::TooManyRequests { .. } => "rate_limited",
+            ApiError::Validation { .. } => "validation_failed",
+            ApiError::NotImplemented(_) => "not_implemented",
+            ApiError::BadGateway(_) => "bad_gateway",
+            ApiError::ServiceUnavailable { .. } => "service_unavailable",
+            ApiError::GatewayTimeout(_) => "gateway_timeout",
+            ApiError::Other(_, _) => return Cow::Owned(format!("http_{}", self.status().as_u16())),
+            ApiError::Multiple(_) => "multiple_errors",
+            ApiError::WithContext(inner, _) => return inner.code(),
+        };
+        Cow::Borrowed(code)
+    }
+
+    /// Name of the variant, used by the `Display` implementation.
+    fn variant_name(&self) -> &'static str {
+        match self {
+            ApiError::BadRequest(_) => "BadRequest",
+            ApiError::NotFound(_) => "NotFound",
+            ApiError::InternalServerError(_) => "InternalServerError",
+            ApiError::Unauthorized { .. } => "Unauthorized",
+            ApiError::Forbidden(_) => "Forbidden",
+            ApiError::Conflict { .. } => "Conflict",
+            ApiError::MethodNotAllowed(_) => "MethodNotAllowed",
+            ApiError::NotAcceptable(_) => "NotAcceptable",
+            ApiError::Gone(_) => "Gone",
+            ApiError::PayloadTooLarge(_) => "PayloadTooLarge",
+            ApiError::UnprocessableEntity { .. } => "UnprocessableEntity",
+            ApiError::TooManyRequests { .. } => "TooManyRequests",
+            ApiError::Validation { .. } => "Validation",
+            ApiError::NotImplemented(_) => "NotImplemented",
+            ApiError::BadGateway(_) => "BadGateway",
+            ApiError::ServiceUnavailable { .. } => "ServiceUnavailable",
+            ApiError::GatewayTimeout(_) => "GatewayTimeout",
+            ApiError::Other(_, _) => "Other",
+            ApiError::Multiple(_) => "Multiple",
+            ApiError::WithContext(inner, _) => inner.variant_name(),
+        }
+    }
+
+    /// Message rendered when no custom message was provided, e.g. `Not Found`.
+    ///
+    /// `into_response` omits the `message` field in that case; this is the string `Display`,
+    /// `into_response_for` and the HTML page use instead, and can be reused for logging or UI
+    /// fallbacks without building a response.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// assert_eq!(ApiError::NotFound(None).default_message(), "Not Found");
+    /// assert_eq!(ApiError::NotFound(Some("No user".into())).default_message(), "Not Found");
+    /// ```
+    pub fn default_message(&self) -> &'static str {
+        match self {
+            ApiError::BadRequest(_) => "Bad Request",
+            ApiError::NotFound(_) => "Not Found",
+            ApiError::InternalServerError(_) => "Internal Server Error",
+            ApiError::Unauthorized { .. } => "Unauthorized",
+            ApiError::Forbidden(_) => "Forbidden",
+            ApiError::Conflict { .. } => "Conflict",
+            ApiError::MethodNotAllowed(_) => "Method Not Allowed",
+            ApiError::NotAcceptable(_) => "Not Acceptable",
+            ApiError::Gone(_) => "Gone",
+            ApiError::PayloadTooLarge(_) => "Payload Too Large",
+            ApiError::UnprocessableEntity { .. } => "Unprocessable Entity",
+            ApiError::TooManyRequests { .. } => "Too Many Requests",
+            ApiError::Validation { .. } => "Validation Failed",
+            ApiError::NotImplemented(_) => "Not Implemented",
+            ApiError::BadGateway(_) => "Bad Gateway",
+            ApiError::ServiceUnavailable { .. } => "Service Unavailable",
+            ApiError::GatewayTimeout(_) => "Gateway Timeout",
+            ApiError::Other(_, _) => "Other Error",
+            ApiError::Multiple(_) => "Multiple Errors",
+            ApiError::WithContext(inner, _) => inner.default_message(),
+        }
+    }
+
+    /// Custom message, if one was provided.
+    fn custom_message(&self) -> Option<&str> {
+        match self {
+            ApiError::BadRequest(message)
+            | ApiError::NotFound(message)
+            | ApiError::InternalServerError(message)
+            | ApiError::Unauthorized { message, .. }
+            | ApiError::Forbidden(message)
+            | ApiError::Conflict { message, .. }
+            | ApiError::MethodNotAllowed(message)
+            | ApiError::NotAcceptable(message)
+            | ApiError::Gone(message)
+            | ApiError::PayloadTooLarge(message)
+            | ApiError::UnprocessableEntity { message, .. }
+            | ApiError::TooManyRequests { message, .. }
+            | ApiError::Validation { message, .. }
+            | ApiError::NotImplemented(message)
+            | ApiError::BadGateway(message)
+            | ApiError::ServiceUnavailable { message, .. }
+            | ApiError::GatewayTimeout(message)
+            | ApiError::Other(_, message) => message.as_deref(),
+            ApiError::Multiple(_) => None,
+            ApiError::WithContext(inner, _) => inner.custom_message(),
+        }
+    }
+
+    /// Field errors keyed by field name; sorted so the body serializes deterministically.
+    fn field_errors(&self) -> BTreeMap<String, String> {
+        match self {
+            ApiError::UnprocessableEntity { errors, .. } => errors.iter().cloned().collect(),
+            ApiError::WithContext(inner, _) => inner.field_errors(),
+            _ => BTreeMap::new(),
+        }
+    }
+
+    /// Field errors in the shape of the JSON body: pointer-keyed errors keep their order.
+    fn body_errors(&self) -> FieldErrors {
+        match self {
+            ApiError::Validation { errors, .. } => FieldErrors::Pointers(
+                errors
+                    .iter()
+                    .map(|(pointer, message)| PointerError {
+                        pointer: pointer.clone(),
+                        message: message.clone(),
+                    })
+                    .collect(),
             ),
+            ApiError::WithContext(inner, _) => inner.body_errors(),
+            _ => FieldErrors::Fields(self.field_errors()),
+        }
+    }
+
+    /// Field named by a `Conflict`, if any.
+    fn conflicting_field(&self) -> Option<String> {
+        match self {
+            ApiError::Conflict {
+                conflicting_field, ..
+            } => conflicting_field.clone(),
+            ApiError::WithContext(inner, _) => inner.conflicting_field(),
+            _ => None,
+        }
+    }
+
+    /// Bodies of the errors aggregated by `Multiple`, without their own timestamps.
+    fn details(&self) -> Vec<ApiErrorBody> {
+        match self {
+            ApiError::Multiple(errors) => errors
+                .iter()
+                .map(|error| ApiErrorBody {
+                    timestamp: None,
+                    ..error.to_body()
+                })
+                .collect(),
+            ApiError::WithContext(inner, _) => inner.details(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Rate limit state of a `TooManyRequests`, if any.
+    fn rate_limit(&self) -> Option<RateLimit> {
+        match self {
+            ApiError::TooManyRequests { rate_limit, .. } => *rate_limit,
+            ApiError::WithContext(inner, _) => inner.rate_limit(),
+            _ => None,
+        }
+    }
+
+    /// Captured backtrace to render, if any; never rendered without the `dev-backtrace`
+    /// feature.
+    #[cfg(feature = "dev-backtrace")]
+    fn backtrace(&self) -> Option<String> {
+        if HIDE_SERVER_ERROR_MESSAGES.load(Ordering::Relaxed) {
+            return None;
+        }
+        self.context()
+            .and_then(|context| context.backtrace.as_ref())
+            .map(|backtrace| backtrace.to_string())
+    }
+
+    #[cfg(not(feature = "dev-backtrace"))]
+    fn backtrace(&self) -> Option<String> {
+        None
+    }
+
+    /// Request ID from the attached context, if any.
+    fn request_id(&self) -> Option<String> {
+        self.context()
+            .and_then(|context| context.request_id.clone())
+    }
+
+    /// Response headers implied by the variant, such as `Retry-After`.
+    fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        match self {
+            ApiError::Unauthorized { challenge, .. } => {
+                // A challenge that isn't a valid header value falls back to the default scheme
+                // rather than dropping the header, which RFC 7235 requires on every 401.
+                let challenge = challenge
+                    .as_deref()
+                    .and_then(|challenge| HeaderValue::from_str(challenge).ok())
+                    .unwrap_or(HeaderValue::from_static("Bearer"));
+                headers.insert(header::WWW_AUTHENTICATE, challenge);
+            }
+            ApiError::TooManyRequests {
+                retry_after,
+                rate_limit,
+                ..
+            } => {
+                if let Some(retry_after) = retry_after {
+                    headers.insert(
+                        header::RETRY_AFTER,
+                        HeaderValue::from(whole_seconds(*retry_after)),
+                    );
+                }
+                if let Some(rate_limit) = rate_limit {
+                    headers.insert(
+                        HeaderName::from_static("x-ratelimit-limit"),
+                        HeaderValue::from(rate_limit.limit),
+                    );
+                    headers.insert(
+                        HeaderName::from_static("x-ratelimit-remaining"),
+                        HeaderValue::from(rate_limit.remaining),
+                    );
+                    headers.insert(
+                        HeaderName::from_static("x-ratelimit-reset"),
+                        HeaderValue::from(rate_limit.reset),
+                    );
+                }
+            }
+            ApiError::ServiceUnavailable {
+                retry_after: Some(retry_after),
+                ..
+            } => {
+                headers.insert(
+                    header::RETRY_AFTER,
+                    HeaderValue::from(whole_seconds(*retry_after)),
+                );
+            }
+            ApiError::WithContext(inner, context) => {
+                headers = inner.headers();
+                // An ID that isn't a valid header value is still echoed in the body.
+                if let Some(request_id) = context
+                    .request_id
+                    .as_deref()
+                    .and_then(|request_id| HeaderValue::from_str(request_id).ok())
+                {
+                    headers.insert(HeaderName::from_static("x-request-id"), request_id);
+                }
+            }
+            _ => {}
+        }
+        headers
+    }
+
+    /// Custom message when provided, default message otherwise.
+    fn message(&self) -> &str {
+        self.custom_message()
+            .unwrap_or_else(|| self.default_message())
+    }
+
+    /// Custom message that may be shown to the client, i.e. not hidden by
+    /// [`hide_server_error_messages`].
+    fn public_custom_message(&self) -> Option<&str> {
+        if self.status().is_server_error() && HIDE_SERVER_ERROR_MESSAGES.load(Ordering::Relaxed) {
+            None
+        } else {
+            self.custom_message()
+        }
+    }
+
+    /// Message rendered in response bodies.
+    fn public_message(&self) -> &str {
+        self.public_custom_message()
+            .unwrap_or_else(|| self.default_message())
+    }
+}
+
+static HIDE_SERVER_ERROR_MESSAGES: AtomicBool = AtomicBool::new(false);
+
+/// Hides custom messages of 5xx errors from clients.
+///
+/// When enabled, every server error (`InternalServerError`, `BadGateway`, `Other(503, ..)`,
+/// ...) renders as if it had no custom message, so internal details such
+/// as database errors don't leak to clients. The custom message is still available through
+/// `Display` and the `tracing` events for logging. 4xx errors are client-facing by design and
+/// always keep their custom message. Disabled by default; typically enabled once at startup
+/// in production.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::errors;
+///
+/// errors::hide_server_error_messages(true);
+/// ```
+pub fn hide_server_error_messages(hide: bool) {
+    HIDE_SERVER_ERROR_MESSAGES.store(hide, Ordering::Relaxed);
+}
+
+/// Formats the error as `Variant: message`, e.g. `NotFound: User not found`.
+///
+/// The default message is used when no custom message was provided.
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.variant_name(), self.message())
+    }
+}
+
+impl std::error::Error for ApiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        let source = self.context()?.source.as_deref()?;
+        Some(source)
+    }
+}
+
+/// JSON body rendered for every `ApiError` response.
+///
+/// Fields serialize in declaration order, and `errors` is sorted by field name, so the
+/// rendered JSON is byte-for-byte stable. Optional fields are omitted when unset. Clients can
+/// deserialize error responses back into this type; unknown fields are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::errors::{ApiError, ApiErrorBody};
+///
+/// let body = ApiError::not_found("User not found").to_body();
+/// let json = serde_json::to_string(&body).unwrap();
+/// # #[cfg(not(feature = "timestamps"))]
+/// assert_eq!(json, r#"{"error":"not_found","status":404,"message":"User not found"}"#);
+///
+/// let parsed: ApiErrorBody = serde_json::from_str(&json).unwrap();
+/// assert_eq!(parsed, body);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ApiErrorBody {
+    /// Machine-readable code, see [`ApiError::code`].
+    pub error: Cow<'static, str>,
+    /// Numeric HTTP status, repeated in the body so it survives logging and proxies.
+    pub status: u16,
+    /// Field named by a `Conflict`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// Custom message; omitted when none was supplied, in which case `error` identifies the
+    /// error on its own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<Cow<'static, str>>,
+    /// Per-field validation errors of `UnprocessableEntity` or `Validation`.
+    #[serde(default, skip_serializing_if = "FieldErrors::is_empty")]
+    pub errors: FieldErrors,
+    /// Rate limit state of `TooManyRequests`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimit>,
+    /// Bodies of the errors aggregated by `Multiple`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<ApiErrorBody>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Backtrace of an `InternalServerError`; set with the `dev-backtrace` feature only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backtrace: Option<String>,
+    /// When the response was generated, in UTC (RFC 3339); set with the `timestamps` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+}
+
+/// Validation errors in an [`ApiErrorBody`]: an object keyed by field name for
+/// `UnprocessableEntity`, or an array of [`PointerError`]s for `Validation`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum FieldErrors {
+    Fields(BTreeMap<String, String>),
+    Pointers(Vec<PointerError>),
+}
+
+impl FieldErrors {
+    /// Whether there are no errors, in which case the field is omitted from the body.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            FieldErrors::Fields(errors) => errors.is_empty(),
+            FieldErrors::Pointers(errors) => errors.is_empty(),
+        }
+    }
+}
+
+impl Default for FieldErrors {
+    fn default() -> Self {
+        FieldErrors::Fields(BTreeMap::new())
+    }
+}
+
+/// Validation message for the part of the request body a JSON Pointer refers to.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PointerError {
+    /// JSON Pointer (RFC 6901), e.g. `/items/0/price`.
+    pub pointer: String,
+    pub message: String,
+}
+
+impl ApiError {
+    /// Renders the error for a client with the given `Accept` header.
+    ///
+    /// `IntoResponse` has no access to the request, so handlers that serve non-JSON clients
+    /// pass the header in explicitly. The JSON body from `into_response` is used whenever
+    /// `application/json` is acceptable (including via `*/*` or `application/*`) and not
+    /// ranked below `text/plain`; otherwise the message is rendered as `text/plain`, including
+    /// for headers accepting neither, e.g. `image/png`. An empty header accepts everything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum::http::{header, HeaderMap};
+    /// use axum::response::Response;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// async fn handler(headers: HeaderMap) -> Response {
+    ///     let accept = headers
+    ///         .get(header::ACCEPT)
+    ///         .and_then(|value| value.to_str().ok())
+    ///         .unwrap_or("*/*");
+    ///     ApiError::not_found("User not found").into_response_for(accept)
+    /// }
+    /// ```
+    pub fn into_response_for(self, accept: &str) -> Response {
+        let json = accept_quality(accept, "application/json");
+        let text = accept_quality(accept, "text/plain");
+
+        if json > 0.0 && json >= text {
+            return self.into_response();
+        }
+
+        #[cfg(feature = "tracing")]
+        self.trace();
+
+        (
+            self.status(),
+            self.headers(),
+            self.public_message().to_string(),
+        )
+            .into_response()
+    }
+}
+
+impl ApiError {
+    /// Renders the error as a minimal HTML page for browser clients.
+    ///
+    /// The page shows the status code, the default message as the title and the message as
+    /// the text, with `Content-Type: text/html`. The message is HTML-escaped, so user-supplied
+    /// text can't inject markup. The default JSON rendering of `into_response` is unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum::response::Response;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// async fn handler() -> Response {
+    ///     ApiError::not_found("This page does not exist").into_html_response()
+    /// }
+    /// ```
+    pub fn into_html_response(self) -> Response {
+        #[cfg(feature = "tracing")]
+        self.trace();
+
+        let status = self.status();
+        let page = format!(
+            "<!DOCTYPE html>\n\
+             <html lang=\"en\">\n\
+             <head>\n\
+             <meta charset=\"utf-8\">\n\
+             <title>{code} {title}</title>\n\
+             <style>body{{font-family:system-ui,sans-serif;margin:4rem auto;max-width:40rem;color:#222}}\
+             h1{{font-size:2rem}}p{{color:#555}}</style>\n\
+             </head>\n\
+             <body>\n\
+             <h1>{code} {title}</h1>\n\
+             <p>{message}</p>\n\
+             </body>\n\
+             </html>\n",
+            code = status.as_u16(),
+            title = escape_html(self.default_message()),
+            message = escape_html(self.public_message()),
+        );
+
+        (status, self.headers(), Html(page)).into_response()
+    }
+}
+
+/// Escapes the characters that are significant in HTML text and attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#x27;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl ApiError {
+    /// Localized default message for the variant, ignoring any custom message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skyak_axum_core::errors::ApiError;
+    /// use skyak_axum_core::locale::Lang;
+    ///
+    /// assert_eq!(ApiError::NotFound(None).default_message_for(Lang::Es), "No encontrado");
+    /// ```
+    pub fn default_message_for(&self, lang: Lang) -> &'static str {
+        locale::default_message(self, lang)
+    }
+
+    /// Message to show a client speaking `lang`: the custom message when one was set (and
+    /// not hidden by [`hide_server_error_messages`]), the localized default otherwise.
+    pub fn message_for(&self, lang: Lang) -> &str {
+        self.public_custom_message()
+            .unwrap_or_else(|| self.default_message_for(lang))
+    }
+
+    /// Renders the JSON error body with the message localized for `lang`.
+    ///
+    /// Custom messages are rendered as-is; only defaults are localized, in which case the
+    /// response advertises the language with a `Content-Language` header, e.g. `es`. English,
+    /// the default, is not advertised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum::http::{header, HeaderMap};
+    /// use axum::response::Response;
+    /// use skyak_axum_core::errors::ApiError;
+    /// use skyak_axum_core::locale::Lang;
+    ///
+    /// async fn handler(headers: HeaderMap) -> Response {
+    ///     let lang = headers
+    ///         .get(header::ACCEPT_LANGUAGE)
+    ///         .and_then(|value| value.to_str().ok())
+    ///         .and_then(Lang::from_accept_language)
+    ///         .unwrap_or(Lang::En);
+    ///     ApiError::NotFound(None).into_localized_response(lang)
+    /// }
+    /// ```
+    pub fn into_localized_response(self, lang: Lang) -> Response {
+        #[cfg(feature = "tracing")]
+        self.trace();
+
+        let mut headers = self.headers();
+        let message = match self.public_custom_message() {
+            Some(message) => Cow::Owned(message.to_string()),
+            None => {
+                // English is the crate's default, so only translations are advertised.
+                if lang != Lang::En {
+                    headers.insert(
+                        header::CONTENT_LANGUAGE,
+                        HeaderValue::from_static(lang.as_str()),
+                    );
+                }
+                Cow::Borrowed(self.default_message_for(lang))
+            }
         };
+        let body = ConfiguredBody::new(self.error_body(Some(message)));
+        json_response(
+            self.status(),
+            headers,
+            HeaderValue::from_static("application/json"),
+            &body,
+        )
+    }
 
-        (status, body).into_response()
+    /// JSON body that `into_response` renders for the error.
+    pub fn to_body(&self) -> ApiErrorBody {
+        let message = self
+            .public_custom_message()
+            .map(|message| Cow::Owned(message.to_string()));
+        self.error_body(message)
+    }
+
+    /// JSON body for the error, rendered with the given message.
+    fn error_body(&self, message: Option<Cow<'static, str>>) -> ApiErrorBody {
+        #[cfg(feature = "timestamps")]
+        let timestamp = Some(timestamp::now_rfc3339());
+        #[cfg(not(feature = "timestamps"))]
+        let timestamp = None;
+
+        ApiErrorBody {
+            error: self.code(),
+            status: self.status().as_u16(),
+            field: self.conflicting_field(),
+            message,
+            errors: self.body_errors(),
+            rate_limit: self.rate_limit(),
+            details: self.details(),
+            request_id: self.request_id(),
+            backtrace: self.backtrace(),
+            timestamp,
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl ApiError {
+    /// Emits one `tracing` event describing the error: `error!` for 5xx, `warn!` otherwise.
+    ///
+    /// The current span is annotated as well, for OpenTelemetry exporters: `http.status_code`
+    /// and `error.type` (the [`ApiError::code`]) are recorded for every error, and
+    /// `otel.status_code = "ERROR"` for 5xx only, as client errors leave a server span's
+    /// status unset. Spans only keep fields they declare, e.g. with `tracing::field::Empty`.
+    ///
+    /// Called from the response conversions, which consume the error, so each error is
+    /// logged at most once.
+    fn trace(&self) {
+        let status = self.status().as_u16();
+        let code = self.code();
+        let message = self.message();
+
+        let span = tracing::Span::current();
+        span.record("http.status_code", status);
+        span.record("error.type", code.as_ref());
+        if self.status().is_server_error() {
+            span.record("otel.status_code", "ERROR");
+        }
+
+        if self.status().is_server_error() {
+            tracing::error!(status, %code, "{message}");
+        } else {
+            tracing::warn!(status, %code, "{message}");
+        }
     }
 }
+
+/// RFC 7807 Problem Details body rendered by [`ApiError::into_problem_response`].
+#[derive(Serialize)]
+struct ProblemDetails {
+    #[serde(rename = "type")]
+    problem_type: &'static str,
+    title: &'static str,
+    status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    field: Option<String>,
+    #[serde(skip_serializing_if = "ProblemErrors::is_empty")]
+    errors: ProblemErrors,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<String>,
+}
+
+/// `errors` extension member of [`ProblemDetails`]: an object keyed by field name, an
+/// array of `{"pointer", "detail"}` objects for JSON Pointer errors, or an array of the
+/// problems aggregated by `Multiple`.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ProblemErrors {
+    Fields(BTreeMap<String, String>),
+    Pointers(Vec<ProblemPointer>),
+    Problems(Vec<ProblemDetails>),
+}
+
+impl ProblemErrors {
+    fn is_empty(&self) -> bool {
+        match self {
+            ProblemErrors::Fields(errors) => errors.is_empty(),
+            ProblemErrors::Pointers(errors) => errors.is_empty(),
+            ProblemErrors::Problems(problems) => problems.is_empty(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ProblemPointer {
+    pointer: String,
+    detail: String,
+}
+
+impl ApiError {
+    /// Renders the error as an RFC 7807 `application/problem+json` response.
+    ///
+    /// This is an opt-in alternative to the default JSON body produced by `into_response`.
+    /// The `title` is the default message for the variant, `status` the HTTP status code and
+    /// `detail` the custom message, which is omitted when none was provided. The `type` is
+    /// always `about:blank`, meaning the problem carries no semantics beyond the status code.
+    /// Field errors are rendered as an `errors` extension member; JSON Pointer errors of
+    /// `Validation` as an array of `{"pointer", "detail"}` objects, in their original order.
+    /// The errors aggregated by `Multiple` are rendered as an `errors` array of problems.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum::response::Response;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// async fn handler() -> Response {
+    ///     ApiError::NotFound(Some("User not found".to_string())).into_problem_response()
+    /// }
+    /// ```
+    pub fn into_problem_response(self) -> Response {
+        #[cfg(feature = "tracing")]
+        self.trace();
+
+        json_response(
+            self.status(),
+            self.headers(),
+            HeaderValue::from_static("application/problem+json"),
+            &self.problem_details(),
+        )
+    }
+
+    /// Problem Details body for the error, also used for each error aggregated by `Multiple`.
+    fn problem_details(&self) -> ProblemDetails {
+        ProblemDetails {
+            problem_type: "about:blank",
+            title: self.default_message(),
+            status: self.status().as_u16(),
+            detail: self.public_custom_message().map(str::to_string),
+            field: self.conflicting_field(),
+            errors: self.problem_errors(),
+            request_id: self.request_id(),
+        }
+    }
+
+    /// Field errors, or the problems aggregated by `Multiple`, in the shape of the Problem
+    /// Details `errors` member.
+    fn problem_errors(&self) -> ProblemErrors {
+        match self {
+            ApiError::Multiple(errors) => {
+                ProblemErrors::Problems(errors.iter().map(ApiError::problem_details).collect())
+            }
+            ApiError::WithContext(inner, _) => inner.problem_errors(),
+            _ => match self.body_errors() {
+                FieldErrors::Fields(errors) => ProblemErrors::Fields(errors),
+                FieldErrors::Pointers(errors) => ProblemErrors::Pointers(
+                    errors
+                        .into_iter()
+                        .map(|error| ProblemPointer {
+                            pointer: error.pointer,
+                            detail: error.message,
+                        })
+                        .collect(),
+                ),
+            },
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        #[cfg(feature = "tracing")]
+        self.trace();
+
+        let body = ConfiguredBody::new(self.to_body());
+        let mut response = json_response(
+            self.status(),
+            self.headers(),
+            HeaderValue::from_static("application/json"),
+            &body,
+        );
+        if self.public_custom_message().is_none() {
+            response
+                .extensions_mut()
+                .insert(DefaultMessageUsed(self.code()));
+        }
+        response
+    }
+}
+
+/// Serializes `body` once and renders it with an exact `Content-Length`.
+///
+/// Serialization failures render a plain-text 500, as `axum::Json` does.
+fn json_response(
+    status: StatusCode,
+    headers: HeaderMap,
+    content_type: HeaderValue,
+    body: &impl Serialize,
+) -> Response {
+    json_parts(status, headers, content_type, body).into_response()
+}
+
+/// Status, headers and serialized body of [`json_response`], independent of the response type.
+fn json_parts(
+    status: StatusCode,
+    mut headers: HeaderMap,
+    content_type: HeaderValue,
+    body: &impl Serialize,
+) -> (StatusCode, HeaderMap, Vec<u8>) {
+    let (status, content_type, bytes) = match serde_json::to_vec(body) {
+        Ok(bytes) => (status, content_type, bytes),
+        Err(error) => {
+            headers = HeaderMap::new();
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                HeaderValue::from_static("text/plain; charset=utf-8"),
+                error.to_string().into_bytes(),
+            )
+        }
+    };
+    headers.insert(header::CONTENT_TYPE, content_type);
+    headers.insert(header::CONTENT_LENGTH, HeaderValue::from(bytes.len()));
+    (status, headers, bytes)
+}
+
+impl ApiError {
+    /// Renders the same response as `into_response`, as a plain `http::Response` with a
+    /// `Full<Bytes>` body.
+    ///
+    /// For tower services that aren't built on axum's `Response` type. Response extensions
+    /// used by this crate's middleware are not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum::http::StatusCode;
+    /// use skyak_axum_core::errors::ApiError;
+    ///
+    /// let response = ApiError::not_found("User not found").into_http_response();
+    /// assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    /// ```
+    pub fn into_http_response(self) -> axum::http::Response<Full<Bytes>> {
+        #[cfg(feature = "tracing")]
+        self.trace();
+
+        let body = ConfiguredBody::new(self.to_body());
+        let (status, headers, bytes) = json_parts(
+            self.status(),
+            self.headers(),
+            HeaderValue::from_static("application/json"),
+            &body,
+        );
+        let mut response = axum::http::Response::new(Full::new(Bytes::from(bytes)));
+        *response.status_mut() = status;
+        *response.headers_mut() = headers;
+        response
+    }
+}
+
+/// Response extension marking an error body rendered with its default message, so
+/// [`DefaultMessages`](crate::middleware::DefaultMessages) can override it.
+#[derive(Clone)]
+pub(crate) struct DefaultMessageUsed(pub(crate) Cow<'static, str>);
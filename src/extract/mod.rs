@@ -0,0 +1,167 @@
+use crate::errors::ApiError;
+use axum::extract::{FromRequest, FromRequestParts, Path, Query, Request};
+use axum::http::request::Parts;
+use axum::Json;
+use serde::de::DeserializeOwned;
+use std::ops::{Deref, DerefMut};
+
+/// JSON body extractor that rejects with an [`ApiError`] instead of axum's plain-text
+/// rejection, so malformed requests get the same JSON error body as every other error.
+///
+/// A body that isn't valid JSON, or isn't sent as `application/json`, is rejected with
+/// `400 Bad Request`; valid JSON that doesn't match `T` is rejected with
+/// `422 Unprocessable Entity`. The message is axum's description of the rejection.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use skyak_axum_core::extract::ApiJson;
+///
+/// #[derive(Deserialize)]
+/// struct CreateUser {
+///     name: String,
+/// }
+///
+/// async fn create_user(ApiJson(user): ApiJson<CreateUser>) -> String {
+///     user.name
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApiJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ApiJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Json::<T>::from_request(req, state).await {
+            Ok(Json(value)) => Ok(ApiJson(value)),
+            Err(rejection) => Err(ApiError::from(rejection)),
+        }
+    }
+}
+
+impl<T> Deref for ApiJson<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for ApiJson<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// Query string extractor that rejects with an [`ApiError`] instead of axum's plain-text
+/// rejection.
+///
+/// Any failure to deserialize the query string into `T` (a missing field, a value of the
+/// wrong type) is rejected with `400 Bad Request`, carrying axum's description of the
+/// problem as the message.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use skyak_axum_core::extract::ApiQuery;
+///
+/// #[derive(Deserialize)]
+/// struct Search {
+///     q: String,
+///     page: Option<u32>,
+/// }
+///
+/// async fn search(query: ApiQuery<Search>) -> String {
+///     format!("{} (page {})", query.q, query.page.unwrap_or(1))
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApiQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for ApiQuery<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match Query::<T>::from_request_parts(parts, state).await {
+            Ok(Query(value)) => Ok(ApiQuery(value)),
+            Err(rejection) => Err(ApiError::from(rejection)),
+        }
+    }
+}
+
+impl<T> Deref for ApiQuery<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for ApiQuery<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// Path parameter extractor that rejects with an [`ApiError`] instead of axum's plain-text
+/// rejection.
+///
+/// Any failure to extract `T` from the matched route's parameters (a segment that doesn't
+/// parse, the wrong number of parameters) is rejected with `400 Bad Request`, carrying axum's
+/// description of the problem as the message.
+///
+/// # Examples
+///
+/// ```
+/// use axum::routing::get;
+/// use axum::Router;
+/// use skyak_axum_core::extract::ApiPath;
+///
+/// async fn get_user(ApiPath(id): ApiPath<u32>) -> String {
+///     format!("user {id}")
+/// }
+///
+/// let app: Router = Router::new().route("/users/{id}", get(get_user));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApiPath<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for ApiPath<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match Path::<T>::from_request_parts(parts, state).await {
+            Ok(Path(value)) => Ok(ApiPath(value)),
+            Err(rejection) => Err(ApiError::from(rejection)),
+        }
+    }
+}
+
+impl<T> Deref for ApiPath<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for ApiPath<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
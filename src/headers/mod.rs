@@ -0,0 +1,170 @@
+use axum::http::HeaderValue;
+use std::fmt;
+use std::time::Duration;
+
+/// Builder for `Cache-Control` header values.
+///
+/// Directives are rendered in a fixed order (`max-age`, `public`/`private`, `no-cache`,
+/// `no-store`, `must-revalidate`) so the same builder always produces the same header.
+/// Contradictory combinations are rejected when the header value is built.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use skyak_axum_core::headers::CacheControl;
+///
+/// let cache_control = CacheControl::new()
+///     .max_age(Duration::from_secs(300))
+///     .public();
+///
+/// assert_eq!(cache_control.header_value().unwrap(), "max-age=300, public");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CacheControl {
+    max_age: Option<Duration>,
+    public: bool,
+    private: bool,
+    no_cache: bool,
+    no_store: bool,
+    must_revalidate: bool,
+}
+
+impl CacheControl {
+    /// Creates an empty `Cache-Control` builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `max-age`, in whole seconds.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Allows shared caches to store the response.
+    pub fn public(mut self) -> Self {
+        self.public = true;
+        self
+    }
+
+    /// Restricts storage to the client's private cache.
+    pub fn private(mut self) -> Self {
+        self.private = true;
+        self
+    }
+
+    /// Requires revalidation with the server before each reuse.
+    pub fn no_cache(mut self) -> Self {
+        self.no_cache = true;
+        self
+    }
+
+    /// Forbids caches from storing the response at all.
+    pub fn no_store(mut self) -> Self {
+        self.no_store = true;
+        self
+    }
+
+    /// Forbids serving the response stale once it has expired.
+    pub fn must_revalidate(mut self) -> Self {
+        self.must_revalidate = true;
+        self
+    }
+
+    /// Renders the directives as a `Cache-Control` header value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidCacheControl`] for contradictory combinations: `public` with
+    /// `private`, or `no-store` with `max-age`.
+    pub fn header_value(&self) -> Result<HeaderValue, InvalidCacheControl> {
+        if self.public && self.private {
+            return Err(InvalidCacheControl(
+                "public and private are mutually exclusive",
+            ));
+        }
+        if self.no_store && self.max_age.is_some() {
+            return Err(InvalidCacheControl(
+                "no-store cannot be combined with max-age",
+            ));
+        }
+
+        let mut directives = Vec::new();
+        if let Some(max_age) = self.max_age {
+            directives.push(format!("max-age={}", max_age.as_secs()));
+        }
+        if self.public {
+            directives.push("public".to_string());
+        }
+        if self.private {
+            directives.push("private".to_string());
+        }
+        if self.no_cache {
+            directives.push("no-cache".to_string());
+        }
+        if self.no_store {
+            directives.push("no-store".to_string());
+        }
+        if self.must_revalidate {
+            directives.push("must-revalidate".to_string());
+        }
+
+        // Directives are ASCII tokens and digits only, so this can't fail.
+        Ok(HeaderValue::from_str(&directives.join(", ")).expect("valid Cache-Control value"))
+    }
+}
+
+/// Error returned by [`CacheControl::header_value`] for contradictory directives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCacheControl(&'static str);
+
+impl fmt::Display for InvalidCacheControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid Cache-Control: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidCacheControl {}
+
+/// Quality value an `Accept` header assigns to `media_type` (e.g. `application/json`).
+///
+/// The most specific matching range wins (`type/subtype` over `type/*` over `*/*`), as per
+/// RFC 9110. Returns `0.0` when the media type is not acceptable; an empty header accepts
+/// everything.
+pub(crate) fn accept_quality(accept: &str, media_type: &str) -> f32 {
+    if accept.trim().is_empty() {
+        return 1.0;
+    }
+    let (media_type, media_subtype) = media_type.split_once('/').unwrap_or((media_type, ""));
+
+    let mut best: Option<(u8, f32)> = None;
+    for range in accept.split(',') {
+        let mut params = range.split(';').map(str::trim);
+        let Some((range_type, range_subtype)) = params.next().and_then(|r| r.split_once('/'))
+        else {
+            continue;
+        };
+
+        let specificity = match (range_type, range_subtype) {
+            ("*", "*") => 1,
+            (t, "*") if t.eq_ignore_ascii_case(media_type) => 2,
+            (t, s)
+                if t.eq_ignore_ascii_case(media_type) && s.eq_ignore_ascii_case(media_subtype) =>
+            {
+                3
+            }
+            _ => continue,
+        };
+        let quality = params
+            .filter_map(|param| param.strip_prefix("q="))
+            .find_map(|q| q.parse::<f32>().ok())
+            .unwrap_or(1.0);
+
+        if best.is_none_or(|(best_specificity, _)| specificity > best_specificity) {
+            best = Some((specificity, quality));
+        }
+    }
+
+    best.map_or(0.0, |(_, quality)| quality)
+}
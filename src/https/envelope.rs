@@ -0,0 +1,104 @@
+use super::{success, ApiResponse, Envelope};
+use serde::Serialize;
+
+/// Shape a project wraps its success bodies in, e.g. `{"data": ...}` or the bare value.
+///
+/// Pick one implementation and use it with [`success_with_envelope`] across all handlers so
+/// every endpoint answers with the same shape. The built-in [`Bare`], [`DataKey`] and
+/// [`ResultKey`] cover the common conventions; other shapes can implement the trait.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+/// use skyak_axum_core::https::SuccessEnvelope;
+///
+/// #[derive(Serialize)]
+/// struct Payload<T> {
+///     payload: T,
+/// }
+///
+/// struct PayloadKey;
+///
+/// impl SuccessEnvelope for PayloadKey {
+///     type Wrapped<T: Serialize> = Payload<T>;
+///
+///     fn wrap<T: Serialize>(data: T) -> Payload<T> {
+///         Payload { payload: data }
+///     }
+/// }
+/// ```
+pub trait SuccessEnvelope {
+    /// Body type `data` is wrapped into.
+    type Wrapped<T: Serialize>: Serialize;
+
+    /// Wraps `data` into the envelope.
+    fn wrap<T: Serialize>(data: T) -> Self::Wrapped<T>;
+}
+
+/// Envelope rendering the data as is, like [`success`].
+#[derive(Debug, Clone, Copy)]
+pub struct Bare;
+
+impl SuccessEnvelope for Bare {
+    type Wrapped<T: Serialize> = T;
+
+    fn wrap<T: Serialize>(data: T) -> T {
+        data
+    }
+}
+
+/// Envelope rendering the data under a `data` key, as an [`Envelope`].
+#[derive(Debug, Clone, Copy)]
+pub struct DataKey;
+
+impl SuccessEnvelope for DataKey {
+    type Wrapped<T: Serialize> = Envelope<T>;
+
+    fn wrap<T: Serialize>(data: T) -> Envelope<T> {
+        Envelope { data }
+    }
+}
+
+/// Envelope rendering the data under a `result` key, as a [`ResultEnvelope`].
+#[derive(Debug, Clone, Copy)]
+pub struct ResultKey;
+
+/// Body wrapping the data under a `result` key, produced by [`ResultKey`].
+#[derive(Serialize, Debug)]
+pub struct ResultEnvelope<T> {
+    pub result: T,
+}
+
+impl SuccessEnvelope for ResultKey {
+    type Wrapped<T: Serialize> = ResultEnvelope<T>;
+
+    fn wrap<T: Serialize>(data: T) -> ResultEnvelope<T> {
+        ResultEnvelope { result: data }
+    }
+}
+
+/// Creates a successful API response with the data wrapped in the envelope `E`.
+///
+/// # Arguments
+///
+/// * `data` - The data to be wrapped and returned.
+///
+/// # Returns
+///
+/// Returns an `ApiResponse` containing the data wrapped by `E`.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::https::{success_with_envelope, ApiResponse, ResultEnvelope, ResultKey};
+///
+/// async fn handler() -> ApiResponse<ResultEnvelope<u32>> {
+///     success_with_envelope::<ResultKey, _>(42)
+/// }
+/// ```
+pub fn success_with_envelope<E: SuccessEnvelope, T: Serialize>(
+    data: T,
+) -> ApiResponse<E::Wrapped<T>> {
+    success(E::wrap(data))
+}
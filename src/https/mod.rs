@@ -1,5 +1,20 @@
+use crate::cookies::CookieBuilder;
 use crate::errors::ApiError;
+use crate::headers::CacheControl;
+use axum::http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
 use axum::Json;
+use serde::Serialize;
+
+mod envelope;
+mod pagination;
+mod stream;
+
+pub use envelope::{
+    success_with_envelope, Bare, DataKey, ResultEnvelope, ResultKey, SuccessEnvelope,
+};
+pub use pagination::{success_cursor, success_paginated, CursorPage, Paginated};
+pub use stream::{success_stream, success_stream_with_trailers, success_try_stream};
 
 /// Response type for API in Axum.
 ///
@@ -24,6 +39,37 @@ use axum::Json;
 /// ```
 pub type ApiResponse<T> = Result<Json<T>, ApiError>;
 
+/// Response type for API routes with a pluggable error type.
+///
+/// Like [`ApiResponse`], but for handlers whose error is a domain type implementing
+/// `IntoResponse` directly. `ApiResult<T>` is the same as `ApiResponse<T>`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::StatusCode;
+/// use axum::response::{IntoResponse, Response};
+/// use axum::Json;
+/// use skyak_axum_core::https::ApiResult;
+///
+/// struct AccountLocked;
+///
+/// impl IntoResponse for AccountLocked {
+///     fn into_response(self) -> Response {
+///         (StatusCode::LOCKED, "account locked").into_response()
+///     }
+/// }
+///
+/// async fn handler() -> ApiResult<String, AccountLocked> {
+///     Err(AccountLocked)
+/// }
+///
+/// async fn default_error() -> ApiResult<String> {
+///     Ok(Json("Success".to_string()))
+/// }
+/// ```
+pub type ApiResult<T, E = ApiError> = Result<Json<T>, E>;
+
 /// Creates a successful API response by wrapping data in `Json` and `Ok`.
 ///
 /// This helper function simplifies the creation of successful API responses by automatically
@@ -94,7 +140,7 @@ pub fn success<T>(data: T) -> ApiResponse<T> {
 ///
 /// // Return an unauthorized error
 /// async fn handle_unauthorized() -> ApiResponse<String> {
-///     error(ApiError::Unauthorized(Some("Invalid token".to_string())))
+///     error(ApiError::unauthorized("Invalid token"))
 /// }
 ///
 /// // Handling a result that might fail
@@ -113,3 +159,978 @@ pub fn success<T>(data: T) -> ApiResponse<T> {
 pub fn error<T>(error: ApiError) -> ApiResponse<T> {
     Err(error)
 }
+
+/// Turns a domain result into an API response: `Ok` becomes a [`success`], `Err` an
+/// [`error`] after conversion with `Into<ApiError>`.
+///
+/// This is the preferred top-level adapter for handlers that delegate to a service, since the
+/// service result can be piped through as is instead of branching on it.
+///
+/// # Arguments
+///
+/// * `result` - The result of the operation.
+///
+/// # Returns
+///
+/// Returns an `ApiResponse<T>`.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::{respond, ApiResponse};
+///
+/// async fn create_user(name: &str) -> Result<u32, ApiError> {
+///     if name.is_empty() {
+///         return Err(ApiError::bad_request("Name must not be empty"));
+///     }
+///     Ok(1)
+/// }
+///
+/// async fn handler() -> ApiResponse<u32> {
+///     respond(create_user("Alice").await)
+/// }
+/// ```
+pub fn respond<T, E: Into<ApiError>>(result: Result<T, E>) -> ApiResponse<T> {
+    match result {
+        Ok(data) => success(data),
+        Err(err) => error(err.into()),
+    }
+}
+
+/// Turns the `Result<Option<T>, E>` of a repository lookup into an API response.
+///
+/// `Ok(Some(data))` becomes a success, `Ok(None)` an `ApiError::NotFound(None)`, and `Err`
+/// is converted with `Into<ApiError>`, so any error type with a `From` impl for `ApiError`
+/// (including `ApiError` itself) can be passed through.
+///
+/// # Arguments
+///
+/// * `result` - The lookup result.
+///
+/// # Returns
+///
+/// Returns an `ApiResponse<T>`.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::{respond_found, ApiResponse};
+///
+/// fn find_user(id: u32) -> Result<Option<String>, ApiError> {
+///     Ok((id == 1).then(|| "Alice".to_string()))
+/// }
+///
+/// async fn get_user() -> ApiResponse<String> {
+///     respond_found(find_user(1))
+/// }
+/// ```
+pub fn respond_found<T, E: Into<ApiError>>(result: Result<Option<T>, E>) -> ApiResponse<T> {
+    match result {
+        Ok(Some(data)) => success(data),
+        Ok(None) => error(ApiError::NotFound(None)),
+        Err(err) => error(err.into()),
+    }
+}
+
+/// Envelope wrapping a successful response body under a `data` key.
+///
+/// Serializes as `{"data": ...}`, mirroring the object shape of error bodies so clients can
+/// handle both uniformly.
+#[derive(Serialize, Debug)]
+pub struct Envelope<T> {
+    pub data: T,
+}
+
+/// Creates a successful API response with the data wrapped in an [`Envelope`].
+///
+/// This is the opt-in counterpart of [`success`]: the body is rendered as `{"data": ...}`
+/// instead of the bare value.
+///
+/// # Arguments
+///
+/// * `data` - The data to be returned under the `data` key.
+///
+/// # Returns
+///
+/// Returns an `ApiResponse<Envelope<T>>` containing the wrapped data.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::https::{ApiResponse, Envelope, success_enveloped};
+///
+/// #[derive(serde::Serialize)]
+/// struct User {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// async fn handle_user() -> ApiResponse<Envelope<User>> {
+///     success_enveloped(User {
+///         id: 1,
+///         name: "Alice".to_string(),
+///     })
+/// }
+/// ```
+pub fn success_enveloped<T>(data: T) -> ApiResponse<Envelope<T>> {
+    success(Envelope { data })
+}
+
+/// Envelope giving plain lists a stable object shape.
+///
+/// Serializes as `{"items": [...], "count": N}`, where `count` always equals `items.len()`.
+#[derive(Serialize, Debug)]
+pub struct ListEnvelope<T> {
+    pub items: Vec<T>,
+    pub count: usize,
+}
+
+impl<T> ListEnvelope<T> {
+    /// Wraps the items, deriving `count` from their length.
+    pub fn new(items: Vec<T>) -> Self {
+        ListEnvelope {
+            count: items.len(),
+            items,
+        }
+    }
+}
+
+/// Creates a successful API response containing a list wrapped in a [`ListEnvelope`].
+///
+/// # Arguments
+///
+/// * `items` - The items to be returned under the `items` key.
+///
+/// # Returns
+///
+/// Returns an `ApiResponse<ListEnvelope<T>>` containing the items and their count.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::https::{success_list, ApiResponse, ListEnvelope};
+///
+/// async fn list_tags() -> ApiResponse<ListEnvelope<String>> {
+///     success_list(vec!["rust".to_string(), "axum".to_string()])
+/// }
+/// ```
+pub fn success_list<T>(items: Vec<T>) -> ApiResponse<ListEnvelope<T>> {
+    success(ListEnvelope::new(items))
+}
+
+/// Creates a `201 Created` response with the data as JSON and an optional `Location` header.
+///
+/// Use this in POST handlers that create a resource. When `location` is provided it is set as
+/// the `Location` header pointing at the new resource. A location that is not a valid header
+/// value yields an `ApiError::InternalServerError` response instead.
+///
+/// # Arguments
+///
+/// * `data` - The created resource, serialized as the JSON body.
+/// * `location` - Optional URL of the created resource.
+///
+/// # Returns
+///
+/// Returns a `Response` with status 201, which composes with the `ApiError` error path in
+/// handlers returning `Result<Response, ApiError>`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::created;
+///
+/// #[derive(serde::Serialize)]
+/// struct User {
+///     id: i32,
+/// }
+///
+/// async fn create_user() -> Result<Response, ApiError> {
+///     let user = User { id: 7 };
+///     Ok(created(user, Some("/users/7")))
+/// }
+/// ```
+pub fn created<T: Serialize>(data: T, location: Option<&str>) -> Response {
+    let location = match location.map(HeaderValue::from_str).transpose() {
+        Ok(location) => location,
+        Err(_) => {
+            return ApiError::InternalServerError(Some("Invalid Location header".to_string()))
+                .into_response()
+        }
+    };
+
+    let mut response = (StatusCode::CREATED, Json(data)).into_response();
+    if let Some(location) = location {
+        response.headers_mut().insert(header::LOCATION, location);
+    }
+    response
+}
+
+/// Creates the response of an upsert: `201 Created` when the resource was created, `200 OK`
+/// when an existing one was updated, both with the data as JSON.
+///
+/// Use [`created`] instead when the new resource's `Location` should be sent as well.
+///
+/// # Arguments
+///
+/// * `data` - The stored resource, serialized as the JSON body.
+/// * `created` - Whether the upsert created the resource.
+///
+/// # Returns
+///
+/// Returns a `Response` with status 201 or 200.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use skyak_axum_core::https::upsert_response;
+///
+/// async fn put_setting() -> Response {
+///     let inserted = true;
+///     upsert_response(serde_json::json!({ "theme": "dark" }), inserted)
+/// }
+/// ```
+pub fn upsert_response<T: Serialize>(data: T, created: bool) -> Response {
+    if created {
+        self::created(data, None)
+    } else {
+        (StatusCode::OK, Json(data)).into_response()
+    }
+}
+
+/// Creates a `204 No Content` response with an empty body.
+///
+/// Use this for DELETE or PUT handlers that succeed without returning data. Unlike
+/// [`success`], no JSON body or `Content-Type` header is emitted.
+///
+/// # Returns
+///
+/// Returns a `Response` with status 204 and an empty body.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::no_content;
+///
+/// async fn delete_user() -> Result<Response, ApiError> {
+///     Ok(no_content())
+/// }
+/// ```
+pub fn no_content() -> Response {
+    StatusCode::NO_CONTENT.into_response()
+}
+
+/// Creates a `200 OK` response with an empty body.
+///
+/// Use this for idempotent actions that succeed without data but keep 200 semantics.
+/// Unlike `success(())`, the body is empty rather than a JSON `null`; unlike
+/// [`no_content`], the status is 200.
+///
+/// # Returns
+///
+/// Returns a `Response` with status 200 and an empty body.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::ok;
+///
+/// async fn resend_invite() -> Result<Response, ApiError> {
+///     Ok(ok())
+/// }
+/// ```
+pub fn ok() -> Response {
+    StatusCode::OK.into_response()
+}
+
+/// Creates a `204 No Content` response with the given headers and an empty body.
+///
+/// Like [`no_content`], no `Content-Type` header is emitted; only the supplied headers are
+/// set, e.g. an `ETag` or `X-Deleted-Id` on a DELETE.
+///
+/// # Arguments
+///
+/// * `headers` - The headers to send.
+///
+/// # Returns
+///
+/// Returns a `Response` with status 204 and an empty body.
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::{HeaderMap, HeaderValue};
+/// use axum::response::Response;
+/// use skyak_axum_core::https::no_content_with_headers;
+///
+/// async fn delete_user() -> Response {
+///     let mut headers = HeaderMap::new();
+///     headers.insert("x-deleted-id", HeaderValue::from_static("42"));
+///     no_content_with_headers(headers)
+/// }
+/// ```
+pub fn no_content_with_headers(headers: HeaderMap) -> Response {
+    (StatusCode::NO_CONTENT, headers).into_response()
+}
+
+/// Creates a `202 Accepted` response with the data as JSON.
+///
+/// Use this for endpoints that enqueue background work and acknowledge the request with a
+/// small body such as a job id.
+///
+/// # Arguments
+///
+/// * `data` - The acknowledgement, serialized as the JSON body.
+///
+/// # Returns
+///
+/// Returns a `Response` with status 202.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::accepted;
+///
+/// #[derive(serde::Serialize)]
+/// struct Job {
+///     job_id: String,
+/// }
+///
+/// async fn start_export() -> Result<Response, ApiError> {
+///     Ok(accepted(Job {
+///         job_id: "job-42".to_string(),
+///     }))
+/// }
+/// ```
+pub fn accepted<T: Serialize>(data: T) -> Response {
+    (StatusCode::ACCEPTED, Json(data)).into_response()
+}
+
+/// Successful JSON response rendered with an explicit status code.
+///
+/// Returned by [`success_with_status`] for handlers that need a 2xx status other than 200
+/// while keeping the `Result<_, ApiError>` shape.
+#[derive(Debug)]
+pub struct WithStatus<T> {
+    pub status: StatusCode,
+    pub data: T,
+}
+
+impl<T: Serialize> IntoResponse for WithStatus<T> {
+    fn into_response(self) -> Response {
+        (self.status, Json(self.data)).into_response()
+    }
+}
+
+/// Creates a successful API response rendered with the given status code.
+///
+/// # Arguments
+///
+/// * `status` - The status code of the response, e.g. `StatusCode::PARTIAL_CONTENT`.
+/// * `data` - The data to be serialized as the JSON body.
+///
+/// # Returns
+///
+/// Returns a `Result<WithStatus<T>, ApiError>` containing the data and status.
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::StatusCode;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::{success_with_status, WithStatus};
+///
+/// async fn partial() -> Result<WithStatus<Vec<u8>>, ApiError> {
+///     success_with_status(StatusCode::PARTIAL_CONTENT, vec![1, 2, 3])
+/// }
+/// ```
+pub fn success_with_status<T>(status: StatusCode, data: T) -> Result<WithStatus<T>, ApiError> {
+    Ok(WithStatus { status, data })
+}
+
+/// Successful JSON response carrying additional response headers.
+///
+/// Returned by [`success_with_headers`]; can also be constructed directly from a `HeaderMap`.
+#[derive(Debug)]
+pub struct WithHeaders<T> {
+    pub headers: HeaderMap,
+    pub data: T,
+}
+
+impl<T: Serialize> IntoResponse for WithHeaders<T> {
+    fn into_response(self) -> Response {
+        (self.headers, Json(self.data)).into_response()
+    }
+}
+
+/// Creates a successful API response with additional response headers.
+///
+/// Header names and values are validated; an invalid name or value yields an
+/// `ApiError::InternalServerError` instead of panicking. Repeated names are appended rather
+/// than overwritten, so multi-valued headers are preserved.
+///
+/// # Arguments
+///
+/// * `data` - The data to be serialized as the JSON body.
+/// * `headers` - Header name/value pairs, e.g. `[("x-request-id", "abc")]`.
+///
+/// # Returns
+///
+/// Returns a `Result<WithHeaders<T>, ApiError>` containing the data and headers.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::{success_with_headers, WithHeaders};
+///
+/// async fn handler() -> Result<WithHeaders<String>, ApiError> {
+///     success_with_headers("Hello".to_string(), [("x-request-id", "abc-123")])
+/// }
+/// ```
+pub fn success_with_headers<T, I, K, V>(data: T, headers: I) -> Result<WithHeaders<T>, ApiError>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: TryInto<HeaderName>,
+    V: TryInto<HeaderValue>,
+{
+    let mut header_map = HeaderMap::new();
+    for (name, value) in headers {
+        let name = name
+            .try_into()
+            .map_err(|_| ApiError::InternalServerError(Some("Invalid header name".to_string())))?;
+        let value = value
+            .try_into()
+            .map_err(|_| ApiError::InternalServerError(Some("Invalid header value".to_string())))?;
+        header_map.append(name, value);
+    }
+
+    Ok(WithHeaders {
+        headers: header_map,
+        data,
+    })
+}
+
+/// Creates a successful API response with a `Cache-Control` header.
+///
+/// # Arguments
+///
+/// * `data` - The data to be serialized as the JSON body.
+/// * `cache_control` - The caching directives to attach.
+///
+/// # Returns
+///
+/// Returns a `Result<WithHeaders<T>, ApiError>`; contradictory directives yield an
+/// `ApiError::InternalServerError`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::headers::CacheControl;
+/// use skyak_axum_core::https::{success_cached, WithHeaders};
+///
+/// async fn handler() -> Result<WithHeaders<String>, ApiError> {
+///     let cache_control = CacheControl::new().max_age(Duration::from_secs(300)).public();
+///     success_cached("Hello".to_string(), cache_control)
+/// }
+/// ```
+pub fn success_cached<T>(data: T, cache_control: CacheControl) -> Result<WithHeaders<T>, ApiError> {
+    let value = cache_control
+        .header_value()
+        .map_err(|error| ApiError::InternalServerError(Some(error.to_string())))?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(header::CACHE_CONTROL, value);
+    Ok(WithHeaders { headers, data })
+}
+
+/// Creates a successful API response with a `Vary` header naming the request headers the
+/// response depends on, so shared caches don't serve it to clients that negotiated differently.
+///
+/// # Arguments
+///
+/// * `data` - The data to be serialized as the JSON body.
+/// * `vary_headers` - Request header names, joined with `, ` in the given order.
+///
+/// # Returns
+///
+/// Returns a `Result<WithHeaders<T>, ApiError>`; a name that isn't a valid header name yields
+/// an `ApiError::InternalServerError`.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::{success_vary, WithHeaders};
+///
+/// async fn handler() -> Result<WithHeaders<String>, ApiError> {
+///     success_vary("Hello".to_string(), &["Accept", "Accept-Language"])
+/// }
+/// ```
+pub fn success_vary<T>(data: T, vary_headers: &[&str]) -> Result<WithHeaders<T>, ApiError> {
+    if vary_headers
+        .iter()
+        .any(|name| HeaderName::from_bytes(name.as_bytes()).is_err())
+    {
+        return Err(ApiError::InternalServerError(Some(
+            "Invalid Vary header name".to_string(),
+        )));
+    }
+    let value = HeaderValue::from_str(&vary_headers.join(", "))
+        .map_err(|_| ApiError::InternalServerError(Some("Invalid Vary header".to_string())))?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(header::VARY, value);
+    Ok(WithHeaders { headers, data })
+}
+
+/// Creates a JSON response with an `ETag`, answering `304 Not Modified` when the client's copy
+/// is current.
+///
+/// The ETag is a 64-bit FNV-1a hash of the serialized body, so it is stable across processes
+/// and deployments for the same payload. `if_none_match` is the raw `If-None-Match` request
+/// header: a list of entity tags or `*`, compared using the weak comparison applicable to
+/// `If-None-Match`. On a match, a bodyless 304 carrying the same `ETag` is returned; otherwise
+/// the full 200 response. Serialization failures yield an `ApiError::InternalServerError`.
+///
+/// # Arguments
+///
+/// * `data` - The data to be serialized as the JSON body.
+/// * `if_none_match` - The `If-None-Match` request header, if present.
+///
+/// # Returns
+///
+/// Returns a `Response` with status 200 or 304.
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::{header, HeaderMap};
+/// use axum::response::Response;
+/// use skyak_axum_core::https::success_with_etag;
+///
+/// async fn handler(headers: HeaderMap) -> Response {
+///     let if_none_match = headers
+///         .get(header::IF_NONE_MATCH)
+///         .and_then(|value| value.to_str().ok());
+///     success_with_etag(vec!["Alice", "Bob"], if_none_match)
+/// }
+/// ```
+pub fn success_with_etag<T: Serialize>(data: T, if_none_match: Option<&str>) -> Response {
+    let body = match serde_json::to_vec(&data) {
+        Ok(body) => body,
+        Err(_) => {
+            return ApiError::InternalServerError(Some("serialization failed".to_string()))
+                .into_response()
+        }
+    };
+    let etag = format!("\"{:016x}\"", fnv1a(&body));
+    // Hex digits and quotes only, so this can't fail.
+    let etag_header = HeaderValue::from_str(&etag).expect("valid ETag value");
+
+    if if_none_match.is_some_and(|header| etag_matches(header, &etag)) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag_header)]).into_response();
+    }
+
+    (
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("application/json"),
+            ),
+            (header::ETAG, etag_header),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+/// 64-bit FNV-1a hash, used for ETags because it is stable across builds.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Weak comparison of an `If-None-Match` header against an entity tag.
+fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    if_none_match.split(',').map(str::trim).any(|candidate| {
+        candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+    })
+}
+
+/// Creates a `301 Moved Permanently` redirect to `location`.
+///
+/// The response has an empty body. A location that is not a valid header value yields an
+/// `ApiError::InternalServerError` response instead.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use skyak_axum_core::https::redirect_permanent;
+///
+/// async fn old_route() -> Response {
+///     redirect_permanent("/v2/users")
+/// }
+/// ```
+pub fn redirect_permanent(location: &str) -> Response {
+    redirect(StatusCode::MOVED_PERMANENTLY, location)
+}
+
+/// Creates a `307 Temporary Redirect` to `location`, preserving the request method.
+///
+/// The response has an empty body. A location that is not a valid header value yields an
+/// `ApiError::InternalServerError` response instead.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use skyak_axum_core::https::redirect_temporary;
+///
+/// async fn maintenance() -> Response {
+///     redirect_temporary("/status")
+/// }
+/// ```
+pub fn redirect_temporary(location: &str) -> Response {
+    redirect(StatusCode::TEMPORARY_REDIRECT, location)
+}
+
+/// Creates a `303 See Other` redirect to `location`, typically after a form POST.
+///
+/// The response has an empty body. A location that is not a valid header value yields an
+/// `ApiError::InternalServerError` response instead.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::see_other;
+///
+/// async fn submit() -> Result<Response, ApiError> {
+///     Ok(see_other("/orders/42"))
+/// }
+/// ```
+pub fn see_other(location: &str) -> Response {
+    redirect(StatusCode::SEE_OTHER, location)
+}
+
+fn redirect(status: StatusCode, location: &str) -> Response {
+    match HeaderValue::from_str(location) {
+        Ok(location) => (status, [(header::LOCATION, location)]).into_response(),
+        Err(_) => ApiError::InternalServerError(Some("Invalid Location header".to_string()))
+            .into_response(),
+    }
+}
+
+/// Creates a successful API response that sets a cookie.
+///
+/// # Arguments
+///
+/// * `data` - The data to be serialized as the JSON body.
+/// * `cookie` - The cookie to send in the `Set-Cookie` header.
+///
+/// # Returns
+///
+/// Returns a `Result<WithHeaders<T>, ApiError>`; a cookie that can't be rendered yields an
+/// `ApiError::InternalServerError`.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::cookies::{CookieBuilder, SameSite};
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::{success_with_cookie, WithHeaders};
+///
+/// async fn login() -> Result<WithHeaders<String>, ApiError> {
+///     let cookie = CookieBuilder::new("session", "abc123")
+///         .http_only()
+///         .secure()
+///         .same_site(SameSite::Strict);
+///     success_with_cookie("Welcome".to_string(), cookie)
+/// }
+/// ```
+pub fn success_with_cookie<T>(data: T, cookie: CookieBuilder) -> Result<WithHeaders<T>, ApiError> {
+    let value = cookie
+        .header_value()
+        .map_err(|error| ApiError::InternalServerError(Some(error.to_string())))?;
+
+    let mut headers = HeaderMap::new();
+    headers.append(header::SET_COOKIE, value);
+    Ok(WithHeaders { headers, data })
+}
+
+/// Creates a successful JSON response and lets the caller adjust it before it is returned.
+///
+/// The body is serialized and the `Content-Type` header set before `parts` runs, so the
+/// closure can add headers (or change the status) without defining a wrapper type per
+/// endpoint, and without its changes being overwritten.
+///
+/// # Arguments
+///
+/// * `data` - The data to be serialized as the JSON body.
+/// * `parts` - Called with the rendered 200 response.
+///
+/// # Returns
+///
+/// Returns the adjusted `Response`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::HeaderValue;
+/// use axum::response::Response;
+/// use skyak_axum_core::https::success_with;
+///
+/// async fn handler() -> Response {
+///     success_with("Hello".to_string(), |response| {
+///         response
+///             .headers_mut()
+///             .insert("x-api-version", HeaderValue::from_static("2"));
+///     })
+/// }
+/// ```
+pub fn success_with<T: Serialize>(data: T, parts: impl FnOnce(&mut Response)) -> Response {
+    let mut response = Json(data).into_response();
+    parts(&mut response);
+    response
+}
+
+/// Fallback handler answering unknown routes with the standard JSON `404 Not Found` error.
+///
+/// # Returns
+///
+/// Returns `ApiError::NotFound(None)`, rendered without a custom message.
+///
+/// # Examples
+///
+/// ```
+/// use axum::routing::get;
+/// use axum::Router;
+/// use skyak_axum_core::https::not_found_handler;
+///
+/// let app: Router = Router::new()
+///     .route("/health", get(|| async { "ok" }))
+///     .fallback(not_found_handler);
+/// ```
+pub async fn not_found_handler() -> ApiError {
+    ApiError::NotFound(None)
+}
+
+/// Creates a successful response with a raw binary body, such as a generated PDF or image.
+///
+/// The body is sent as-is with the given `Content-Type` and a `Content-Length`, bypassing
+/// `Json`, so it fits handlers returning `Result<Response, ApiError>`.
+///
+/// # Arguments
+///
+/// * `bytes` - The response body.
+/// * `content_type` - The media type of the body, e.g. `application/pdf`.
+///
+/// # Returns
+///
+/// Returns a `Response` with status 200; a content type that isn't a valid header value
+/// yields an `ApiError::InternalServerError` response.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::success_bytes;
+///
+/// async fn avatar() -> Result<Response, ApiError> {
+///     let png = vec![0x89, b'P', b'N', b'G'];
+///     Ok(success_bytes(png, "image/png"))
+/// }
+/// ```
+pub fn success_bytes(bytes: Vec<u8>, content_type: &str) -> Response {
+    let Ok(content_type) = HeaderValue::from_str(content_type) else {
+        return ApiError::InternalServerError(Some("Invalid Content-Type header".to_string()))
+            .into_response();
+    };
+
+    (
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::CONTENT_LENGTH, HeaderValue::from(bytes.len())),
+        ],
+        bytes,
+    )
+        .into_response()
+}
+
+/// Creates a file download response with a `Content-Disposition: attachment` header.
+///
+/// The filename is sent as a quoted `filename` parameter, with `"` and `\` escaped and
+/// characters outside printable ASCII replaced by `_`. When that changes the name, the exact
+/// name is also sent as an RFC 5987 `filename*=UTF-8''...` parameter, which clients prefer.
+///
+/// # Arguments
+///
+/// * `bytes` - The file contents.
+/// * `filename` - The name the client should save the file as.
+/// * `content_type` - The media type of the file.
+///
+/// # Returns
+///
+/// Returns a `Response` with status 200, the given `Content-Type` and a `Content-Length`; a
+/// content type that isn't a valid header value yields an `ApiError::InternalServerError`
+/// response.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use skyak_axum_core::https::download;
+///
+/// async fn export() -> Response {
+///     download(b"id,name\n1,Alice\n".to_vec(), "users.csv", "text/csv")
+/// }
+/// ```
+pub fn download(bytes: Vec<u8>, filename: &str, content_type: &str) -> Response {
+    let Ok(content_type) = HeaderValue::from_str(content_type) else {
+        return ApiError::InternalServerError(Some("Invalid Content-Type header".to_string()))
+            .into_response();
+    };
+
+    (
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::CONTENT_LENGTH, HeaderValue::from(bytes.len())),
+            (header::CONTENT_DISPOSITION, content_disposition(filename)),
+        ],
+        bytes,
+    )
+        .into_response()
+}
+
+/// `attachment` disposition for `filename`, with an RFC 5987 `filename*` for names that
+/// aren't printable ASCII.
+fn content_disposition(filename: &str) -> HeaderValue {
+    let mut value = String::from("attachment; filename=\"");
+    let mut lossy = false;
+    for c in filename.chars() {
+        match c {
+            '"' | '\\' => {
+                value.push('\\');
+                value.push(c);
+            }
+            ' '..='~' => value.push(c),
+            _ => {
+                value.push('_');
+                lossy = true;
+            }
+        }
+    }
+    value.push('"');
+
+    if lossy {
+        value.push_str("; filename*=UTF-8''");
+        for byte in filename.bytes() {
+            match byte {
+                b'A'..=b'Z'
+                | b'a'..=b'z'
+                | b'0'..=b'9'
+                | b'!'
+                | b'#'
+                | b'$'
+                | b'&'
+                | b'+'
+                | b'-'
+                | b'.'
+                | b'^'
+                | b'_'
+                | b'`'
+                | b'|'
+                | b'~' => value.push(char::from(byte)),
+                _ => value.push_str(&format!("%{byte:02X}")),
+            }
+        }
+    }
+
+    // Only printable ASCII was pushed, which is always a valid header value.
+    HeaderValue::from_str(&value).unwrap_or(HeaderValue::from_static("attachment"))
+}
+
+/// Creates a successful JSON response with a custom media type, e.g. a vendor type such as
+/// `application/vnd.myapp.v2+json` for versioned APIs.
+///
+/// # Arguments
+///
+/// * `data` - The data to be serialized as the JSON body.
+/// * `media_type` - The `Content-Type` to send instead of `application/json`.
+///
+/// # Returns
+///
+/// Returns a `Response` with status 200; a media type that isn't a valid header value, or
+/// data that fails to serialize, yields an `ApiError::InternalServerError` response.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use skyak_axum_core::https::success_media_type;
+///
+/// async fn handler() -> Response {
+///     success_media_type("Hello".to_string(), "application/vnd.myapp.v2+json")
+/// }
+/// ```
+pub fn success_media_type<T: Serialize>(data: T, media_type: &str) -> Response {
+    let Ok(media_type) = HeaderValue::from_str(media_type) else {
+        return ApiError::InternalServerError(Some("Invalid Content-Type header".to_string()))
+            .into_response();
+    };
+    let body = match serde_json::to_vec(&data) {
+        Ok(body) => body,
+        Err(_) => {
+            return ApiError::InternalServerError(Some("serialization failed".to_string()))
+                .into_response()
+        }
+    };
+
+    ([(header::CONTENT_TYPE, media_type)], body).into_response()
+}
+
+/// Creates a successful JSON response, serializing the data eagerly.
+///
+/// `axum::Json` serializes when the response is produced and answers failures (e.g. a map
+/// with non-string keys) with a plain-text 500. This variant reports them through the
+/// standard error body instead.
+///
+/// # Arguments
+///
+/// * `data` - The data to be serialized as the JSON body.
+///
+/// # Returns
+///
+/// Returns a `Response` with status 200, or an `ApiError::InternalServerError` response with
+/// the message `serialization failed`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use skyak_axum_core::https::success_checked;
+///
+/// async fn handler() -> Response {
+///     success_checked(vec!["Alice", "Bob"])
+/// }
+/// ```
+pub fn success_checked<T: Serialize>(data: T) -> Response {
+    success_media_type(data, "application/json")
+}
@@ -0,0 +1,113 @@
+use super::{success, ApiResponse};
+use serde::Serialize;
+
+/// Offset-paginated list of items with pagination metadata.
+///
+/// Serializes as `{"items": [...], "total": 42, "page": 1, "per_page": 10, "total_pages": 5}`.
+/// `total_pages` is computed from `total` and `per_page`, rounding up.
+#[derive(Serialize, Debug)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+    pub page: u32,
+    pub per_page: u32,
+    pub total_pages: u64,
+}
+
+impl<T> Paginated<T> {
+    /// Creates a page, computing `total_pages` from `total` and `per_page`.
+    ///
+    /// A `per_page` of zero yields zero pages instead of dividing by zero.
+    pub fn new(items: Vec<T>, total: u64, page: u32, per_page: u32) -> Self {
+        let total_pages = match per_page {
+            0 => 0,
+            per_page => total.div_ceil(u64::from(per_page)),
+        };
+
+        Paginated {
+            items,
+            total,
+            page,
+            per_page,
+            total_pages,
+        }
+    }
+}
+
+/// Creates a successful API response containing a [`Paginated`] page.
+///
+/// # Arguments
+///
+/// * `items` - The items of the current page.
+/// * `total` - The total number of items across all pages.
+/// * `page` - The current page number.
+/// * `per_page` - The maximum number of items per page.
+///
+/// # Returns
+///
+/// Returns an `ApiResponse<Paginated<T>>` containing the page and its metadata.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::https::{success_paginated, ApiResponse, Paginated};
+///
+/// async fn list_users() -> ApiResponse<Paginated<String>> {
+///     let users = vec!["Alice".to_string(), "Bob".to_string()];
+///     success_paginated(users, 12, 1, 2)
+/// }
+/// ```
+pub fn success_paginated<T>(
+    items: Vec<T>,
+    total: u64,
+    page: u32,
+    per_page: u32,
+) -> ApiResponse<Paginated<T>> {
+    success(Paginated::new(items, total, page, per_page))
+}
+
+/// Cursor-paginated list of items for feeds that can't use offset pagination.
+///
+/// Serializes as `{"items": [...], "next_cursor": "abc", "has_more": true}`. `next_cursor` is
+/// `null` on the final page.
+#[derive(Serialize, Debug)]
+pub struct CursorPage<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+impl<T> CursorPage<T> {
+    /// Creates a page; `has_more` is true whenever a `next_cursor` is present.
+    pub fn new(items: Vec<T>, next_cursor: Option<String>) -> Self {
+        CursorPage {
+            items,
+            has_more: next_cursor.is_some(),
+            next_cursor,
+        }
+    }
+}
+
+/// Creates a successful API response containing a [`CursorPage`].
+///
+/// # Arguments
+///
+/// * `items` - The items of the current page.
+/// * `next_cursor` - The cursor of the next page, or `None` on the final page.
+///
+/// # Returns
+///
+/// Returns an `ApiResponse<CursorPage<T>>` containing the page.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::https::{success_cursor, ApiResponse, CursorPage};
+///
+/// async fn feed() -> ApiResponse<CursorPage<String>> {
+///     success_cursor(vec!["post-1".to_string()], Some("cursor-2".to_string()))
+/// }
+/// ```
+pub fn success_cursor<T>(items: Vec<T>, next_cursor: Option<String>) -> ApiResponse<CursorPage<T>> {
+    success(CursorPage::new(items, next_cursor))
+}
@@ -0,0 +1,240 @@
+use crate::errors::ApiError;
+use axum::body::{Body, Bytes};
+use axum::http::{header, HeaderMap, HeaderName, HeaderValue};
+use axum::response::{IntoResponse, Response};
+use futures_util::stream::{self, Stream, StreamExt};
+use http_body::Frame;
+use serde::Serialize;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+/// Trailer carrying the numeric status of an error that ended a stream.
+const X_ERROR_STATUS: HeaderName = HeaderName::from_static("x-error-status");
+/// Trailer carrying the [`ApiError::code`] of an error that ended a stream.
+const X_ERROR_CODE: HeaderName = HeaderName::from_static("x-error-code");
+/// Trailer carrying the message of an error that ended a stream.
+const X_ERROR_MESSAGE: HeaderName = HeaderName::from_static("x-error-message");
+
+/// Creates a successful response streaming items as newline-delimited JSON.
+///
+/// Each item is serialized on its own line as it is produced, with
+/// `Content-Type: application/x-ndjson`, so large exports don't have to be buffered in
+/// memory. The status is 200 and is sent before the first item; an item that fails to
+/// serialize aborts the body, which clients see as a truncated response rather than a
+/// silently missing line.
+///
+/// # Arguments
+///
+/// * `stream` - The items to send, in order.
+///
+/// # Returns
+///
+/// Returns a streaming `Response`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use futures_util::stream;
+/// use skyak_axum_core::https::success_stream;
+///
+/// #[derive(serde::Serialize)]
+/// struct Row {
+///     id: u32,
+/// }
+///
+/// async fn export() -> Response {
+///     success_stream(stream::iter((1..=3).map(|id| Row { id })))
+/// }
+/// ```
+pub fn success_stream<S>(stream: S) -> Response
+where
+    S: Stream + Send + 'static,
+    S::Item: Serialize,
+{
+    let lines = stream.map(|item| {
+        let mut line = serde_json::to_vec(&item)?;
+        line.push(b'\n');
+        Ok::<_, serde_json::Error>(line)
+    });
+
+    (
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/x-ndjson"),
+        )],
+        Body::from_stream(lines),
+    )
+        .into_response()
+}
+
+/// Creates a successful response streaming items as newline-delimited JSON, reporting an
+/// error that ends the stream in HTTP trailers.
+///
+/// Like [`success_stream`], but for fallible sources: once the status has been sent, a
+/// mid-stream failure can no longer change it, so the first `Err` ends the body with
+/// `grpc-status`-style trailers instead: `X-Error-Status` (e.g. `503`), `X-Error-Code` (the
+/// [`ApiError::code`]) and `X-Error-Message` (the public message, when it is a valid header
+/// value). The trailer names are announced in a `Trailer` header. Trailers are delivered over
+/// HTTP/2 and HTTP/3, and over HTTP/1.1 to clients that send `TE: trailers`.
+///
+/// # Arguments
+///
+/// * `stream` - The items to send, in order, ending at the first error.
+///
+/// # Returns
+///
+/// Returns a streaming `Response`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use futures_util::stream;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::success_stream_with_trailers;
+///
+/// async fn export() -> Response {
+///     let rows = vec![Ok(1), Ok(2), Err(ApiError::service_unavailable("Replica lost"))];
+///     success_stream_with_trailers(stream::iter(rows))
+/// }
+/// ```
+pub fn success_stream_with_trailers<S, T>(stream: S) -> Response
+where
+    S: Stream<Item = Result<T, ApiError>> + Send + 'static,
+    T: Serialize,
+{
+    trailer_response(Box::pin(stream))
+}
+
+/// Creates a response streaming the items of a `TryStream` as newline-delimited JSON, where
+/// an error maps to its status when possible.
+///
+/// The first item is awaited before anything is sent, so the error matters on where it
+/// occurs:
+///
+/// - **Before the first item**: nothing has been sent yet, so the response is the error's
+///   regular JSON response with its own status, e.g. `404 Not Found`.
+/// - **After the first item**: the `200 OK` status is already on its way, so the body ends
+///   and the error is reported in trailers, as with [`success_stream_with_trailers`].
+///
+/// An empty stream yields a `200 OK` with an empty body.
+///
+/// # Arguments
+///
+/// * `stream` - The items to send, in order, ending at the first error.
+///
+/// # Returns
+///
+/// Returns a streaming `Response`, or the error response when the stream fails immediately.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::Response;
+/// use futures_util::stream;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::https::success_try_stream;
+///
+/// async fn export() -> Response {
+///     let rows = vec![Ok::<_, ApiError>(1), Ok(2)];
+///     success_try_stream(stream::iter(rows)).await
+/// }
+/// ```
+pub async fn success_try_stream<S, T>(stream: S) -> Response
+where
+    S: Stream<Item = Result<T, ApiError>> + Send + 'static,
+    T: Serialize + Send + 'static,
+{
+    let mut stream = Box::pin(stream);
+    match stream.next().await {
+        Some(Err(error)) => error.into_response(),
+        Some(Ok(first)) => {
+            trailer_response(Box::pin(stream::once(async { Ok(first) }).chain(stream)))
+        }
+        None => trailer_response(stream),
+    }
+}
+
+/// NDJSON response for [`TrailerBody`], announcing its trailers.
+fn trailer_response<S, T>(stream: Pin<Box<S>>) -> Response
+where
+    S: Stream<Item = Result<T, ApiError>> + Send + 'static,
+    T: Serialize,
+{
+    (
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("application/x-ndjson"),
+            ),
+            (
+                header::TRAILER,
+                HeaderValue::from_static("x-error-status, x-error-code, x-error-message"),
+            ),
+        ],
+        Body::new(TrailerBody {
+            stream,
+            done: false,
+        }),
+    )
+        .into_response()
+}
+
+/// NDJSON body ending with error trailers, see [`success_stream_with_trailers`].
+struct TrailerBody<S> {
+    stream: Pin<Box<S>>,
+    done: bool,
+}
+
+impl<S, T> http_body::Body for TrailerBody<S>
+where
+    S: Stream<Item = Result<T, ApiError>>,
+    T: Serialize,
+{
+    type Data = Bytes;
+    type Error = serde_json::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, Self::Error>>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+        match ready!(self.stream.as_mut().poll_next(cx)) {
+            Some(Ok(item)) => {
+                let mut line = serde_json::to_vec(&item)?;
+                line.push(b'\n');
+                Poll::Ready(Some(Ok(Frame::data(Bytes::from(line)))))
+            }
+            Some(Err(error)) => {
+                self.done = true;
+                Poll::Ready(Some(Ok(Frame::trailers(error_trailers(&error)))))
+            }
+            None => {
+                self.done = true;
+                Poll::Ready(None)
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.done
+    }
+}
+
+/// Trailers describing the error that ended a stream.
+fn error_trailers(error: &ApiError) -> HeaderMap {
+    let body = error.to_body();
+    let mut trailers = HeaderMap::new();
+    trailers.insert(X_ERROR_STATUS, HeaderValue::from(body.status));
+    if let Ok(code) = HeaderValue::from_str(&body.error) {
+        trailers.insert(X_ERROR_CODE, code);
+    }
+    let message = body.message.as_deref().unwrap_or(error.default_message());
+    if let Ok(message) = HeaderValue::from_str(message) {
+        trailers.insert(X_ERROR_MESSAGE, message);
+    }
+    trailers
+}
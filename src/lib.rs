@@ -1,2 +1,27 @@
+pub mod cookies;
 pub mod errors;
+pub mod extract;
+pub mod headers;
 pub mod https;
+pub mod locale;
+pub mod middleware;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[doc(hidden)]
+pub mod __private {
+    // Re-exported for code generated by `skyak_axum_core_derive`.
+    pub use axum;
+
+    use crate::errors::{self, ApiError};
+    use axum::http::StatusCode;
+
+    /// Variant matching `status`, so derived errors render like hand-written ones; codes
+    /// without a dedicated variant become `Other`.
+    pub fn api_error(status: u16, message: String) -> ApiError {
+        match StatusCode::from_u16(status) {
+            Ok(status) => errors::for_status(status, Some(message)),
+            Err(_) => ApiError::Other(status, Some(message)),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::errors::ApiError;
+
+/// Languages the crate ships default error messages for.
+///
+/// # Examples
+///
+/// ```
+/// use skyak_axum_core::locale::Lang;
+///
+/// assert_eq!(Lang::from_accept_language("es-ES,es;q=0.9,en;q=0.8"), Some(Lang::Es));
+/// assert_eq!(Lang::from_accept_language("fr"), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Lang {
+    En,
+    Es,
+}
+
+impl Lang {
+    /// BCP 47 language tag, e.g. `es`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::Es => "es",
+        }
+    }
+
+    /// Parses a language tag such as `es` or `es-MX`, matching on the primary subtag.
+    pub fn parse(tag: &str) -> Option<Lang> {
+        let primary = tag.split('-').next().unwrap_or(tag).trim();
+        if primary.eq_ignore_ascii_case("en") {
+            Some(Lang::En)
+        } else if primary.eq_ignore_ascii_case("es") {
+            Some(Lang::Es)
+        } else {
+            None
+        }
+    }
+
+    /// Picks the supported language with the highest quality from an `Accept-Language`
+    /// header, or `None` when none of them is supported.
+    pub fn from_accept_language(accept_language: &str) -> Option<Lang> {
+        let mut best: Option<(Lang, f32)> = None;
+        for range in accept_language.split(',') {
+            let mut params = range.split(';').map(str::trim);
+            let Some(lang) = params.next().and_then(Lang::parse) else {
+                continue;
+            };
+            let quality = params
+                .filter_map(|param| param.strip_prefix("q="))
+                .find_map(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            if quality > 0.0 && best.is_none_or(|(_, best_quality)| quality > best_quality) {
+                best = Some((lang, quality));
+            }
+        }
+        best.map(|(lang, _)| lang)
+    }
+}
+
+/// Localized default message for the error's variant.
+pub(crate) fn default_message(error: &ApiError, lang: Lang) -> &'static str {
+    match lang {
+        Lang::En => error.default_message(),
+        Lang::Es => spanish(error.inner()),
+    }
+}
+
+fn spanish(error: &ApiError) -> &'static str {
+    match error {
+        ApiError::BadRequest(_) => "Solicitud incorrecta",
+        ApiError::NotFound(_) => "No encontrado",
+        ApiError::InternalServerError(_) => "Error interno del servidor",
+        ApiError::Unauthorized { .. } => "No autorizado",
+        ApiError::Forbidden(_) => "Prohibido",
+        ApiError::Conflict { .. } => "Conflicto",
+        ApiError::MethodNotAllowed(_) => "Método no permitido",
+        ApiError::NotAcceptable(_) => "No aceptable",
+        ApiError::Gone(_) => "Ya no disponible",
+        ApiError::PayloadTooLarge(_) => "Contenido demasiado grande",
+        ApiError::UnprocessableEntity { .. } => "Entidad no procesable",
+        ApiError::TooManyRequests { .. } => "Demasiadas solicitudes",
+        ApiError::Validation { .. } => "Validación fallida",
+        ApiError::NotImplemented(_) => "No implementado",
+        ApiError::BadGateway(_) => "Puerta de enlace incorrecta",
+        ApiError::ServiceUnavailable { .. } => "Servicio no disponible",
+        ApiError::GatewayTimeout(_) => "Tiempo de espera de la puerta de enlace agotado",
+        ApiError::Other(_, _) => "Otro error",
+        ApiError::Multiple(_) => "Varios errores",
+        ApiError::WithContext(inner, _) => spanish(inner),
+    }
+}
@@ -0,0 +1,125 @@
+use crate::errors::ApiError;
+use axum::http::Request;
+use axum::response::{IntoResponse, Response};
+use std::any::Any;
+use std::convert::Infallible;
+use std::future::Future;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// Layer turning handler panics into a standard `500 Internal Server Error` JSON response.
+///
+/// Without it, a panicking handler aborts the connection without a response body matching
+/// the rest of the API. The panic message is left out of the body unless
+/// [`CatchPanic::expose_message`] is set, which is meant for development; it is subject to
+/// [`hide_server_error_messages`](crate::errors::hide_server_error_messages) like any other
+/// 5xx message.
+///
+/// # Examples
+///
+/// ```
+/// use axum::routing::get;
+/// use axum::Router;
+/// use skyak_axum_core::middleware::CatchPanic;
+///
+/// let app: Router = Router::new()
+///     .route("/", get(|| async { "ok" }))
+///     .layer(CatchPanic::new());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CatchPanic {
+    expose_message: bool,
+}
+
+impl CatchPanic {
+    /// Creates a layer that renders panics without a message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renders the panic message as the error message, for development builds.
+    pub fn expose_message(mut self, expose: bool) -> Self {
+        self.expose_message = expose;
+        self
+    }
+}
+
+impl<S> Layer<S> for CatchPanic {
+    type Service = CatchPanicService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CatchPanicService {
+            inner,
+            expose_message: self.expose_message,
+        }
+    }
+}
+
+/// Service produced by [`CatchPanic`].
+#[derive(Debug, Clone)]
+pub struct CatchPanicService<S> {
+    inner: S,
+    expose_message: bool,
+}
+
+impl<S, B> Service<Request<B>> for CatchPanicService<S>
+where
+    S: Service<Request<B>, Response = Response, Error = Infallible> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        let expose_message = self.expose_message;
+        match catch_unwind(AssertUnwindSafe(|| self.inner.call(request))) {
+            Ok(future) => Box::pin(CatchUnwind {
+                future: Box::pin(future),
+                expose_message,
+            }),
+            Err(panic) => {
+                let response = panic_response(panic, expose_message);
+                Box::pin(async move { Ok(response) })
+            }
+        }
+    }
+}
+
+/// Polls the inner future, answering with the panic response if polling panics.
+struct CatchUnwind<F> {
+    future: Pin<Box<F>>,
+    expose_message: bool,
+}
+
+impl<F> Future for CatchUnwind<F>
+where
+    F: Future<Output = Result<Response, Infallible>>,
+{
+    type Output = Result<Response, Infallible>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let expose_message = self.expose_message;
+        match catch_unwind(AssertUnwindSafe(|| self.future.as_mut().poll(cx))) {
+            Ok(poll) => poll,
+            Err(panic) => Poll::Ready(Ok(panic_response(panic, expose_message))),
+        }
+    }
+}
+
+fn panic_response(panic: Box<dyn Any + Send>, expose_message: bool) -> Response {
+    let message = expose_message.then(|| {
+        panic
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "handler panicked".to_string())
+    });
+    ApiError::InternalServerError(message).into_response()
+}
@@ -0,0 +1,138 @@
+use crate::errors::{current_config, DefaultMessageUsed};
+use axum::body::{to_bytes, Body};
+use axum::http::{header, HeaderValue, Request};
+use axum::response::Response;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// Layer overriding the default error messages of the routes it wraps.
+///
+/// `IntoResponse` has no access to the request, so the override can't be applied when an
+/// [`ApiError`](crate::errors::ApiError) is rendered. Instead the layer rewrites the response:
+/// when an error rendered by `into_response` had no custom message and an override is
+/// registered for its [`code`](crate::errors::ApiError::code), the override is set as the
+/// message field of the JSON body (`message`, unless [configured](crate::errors::configure)
+/// otherwise). Custom messages and other responses pass through untouched.
+///
+/// # Examples
+///
+/// ```
+/// use axum::routing::get;
+/// use axum::Router;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::middleware::DefaultMessages;
+///
+/// async fn handler() -> ApiError {
+///     ApiError::NotFound(None)
+/// }
+///
+/// let public: Router = Router::new()
+///     .route("/users", get(handler))
+///     .layer(DefaultMessages::new().message("not_found", "Nothing to see here"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DefaultMessages {
+    messages: Arc<HashMap<Cow<'static, str>, String>>,
+}
+
+impl DefaultMessages {
+    /// Creates a layer with no overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the default message of errors with the given code, e.g. `"not_found"`.
+    pub fn message(
+        mut self,
+        code: impl Into<Cow<'static, str>>,
+        message: impl Into<String>,
+    ) -> Self {
+        Arc::make_mut(&mut self.messages).insert(code.into(), message.into());
+        self
+    }
+}
+
+impl<S> Layer<S> for DefaultMessages {
+    type Service = DefaultMessagesService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        DefaultMessagesService {
+            inner,
+            messages: self.messages.clone(),
+        }
+    }
+}
+
+/// Service produced by [`DefaultMessages`].
+#[derive(Debug, Clone)]
+pub struct DefaultMessagesService<S> {
+    inner: S,
+    messages: Arc<HashMap<Cow<'static, str>, String>>,
+}
+
+impl<S, B> Service<Request<B>> for DefaultMessagesService<S>
+where
+    S: Service<Request<B>, Response = Response, Error = Infallible> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        let future = self.inner.call(request);
+        let messages = self.messages.clone();
+
+        Box::pin(async move {
+            let response = future.await?;
+            let Some(message) = response
+                .extensions()
+                .get::<DefaultMessageUsed>()
+                .and_then(|used| messages.get(&used.0))
+            else {
+                return Ok(response);
+            };
+            Ok(replace_message(response, message).await)
+        })
+    }
+}
+
+/// Rewrites the message field of an `ApiError` JSON body.
+///
+/// Bodies that aren't JSON objects, e.g. rewritten by an inner layer, pass through unchanged.
+async fn replace_message(response: Response, message: &str) -> Response {
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = to_bytes(body, usize::MAX).await else {
+        parts.headers.remove(header::CONTENT_LENGTH);
+        return Response::from_parts(parts, Body::empty());
+    };
+    let Ok(mut json) = serde_json::from_slice::<Value>(&bytes) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+    let Some(fields) = json.as_object_mut() else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    fields.insert(
+        current_config().message_key.into_owned(),
+        Value::String(message.to_string()),
+    );
+    let bytes = serde_json::to_vec(&json).expect("JSON value serializes");
+    parts
+        .headers
+        .insert(header::CONTENT_LENGTH, HeaderValue::from(bytes.len()));
+    // The message is no longer the default, so outer layers keep this override.
+    parts.extensions.remove::<DefaultMessageUsed>();
+    Response::from_parts(parts, Body::from(bytes))
+}
@@ -0,0 +1,99 @@
+use crate::errors::ApiError;
+use axum::body::Body;
+use axum::http::{header, Request};
+use axum::response::{IntoResponse, Response};
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// Layer rejecting request bodies larger than a fixed number of bytes with the standard JSON
+/// `413 Payload Too Large` error.
+///
+/// A declared `Content-Length` over the limit is rejected without reading the body; other
+/// bodies, including chunked ones, are buffered up to the limit before the handler runs.
+///
+/// # Examples
+///
+/// ```
+/// use axum::routing::post;
+/// use axum::Router;
+/// use skyak_axum_core::middleware::limit_body;
+///
+/// let app: Router = Router::new()
+///     .route("/", post(|body: String| async move { body }))
+///     .layer(limit_body(64 * 1024));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LimitBody {
+    max_bytes: usize,
+}
+
+/// Creates a [`LimitBody`] layer allowing bodies of up to `max_bytes`.
+pub fn limit_body(max_bytes: usize) -> LimitBody {
+    LimitBody { max_bytes }
+}
+
+impl<S> Layer<S> for LimitBody {
+    type Service = LimitBodyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        LimitBodyService {
+            inner,
+            max_bytes: self.max_bytes,
+        }
+    }
+}
+
+/// Service produced by [`LimitBody`].
+#[derive(Debug, Clone)]
+pub struct LimitBodyService<S> {
+    inner: S,
+    max_bytes: usize,
+}
+
+impl<S> Service<Request<Body>> for LimitBodyService<S>
+where
+    S: Service<Request<Body>, Response = Response, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let max_bytes = self.max_bytes;
+        let declared = request
+            .headers()
+            .get(header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok());
+        if declared.is_some_and(|length| length > max_bytes as u64) {
+            return Box::pin(async { Ok(ApiError::PayloadTooLarge(None).into_response()) });
+        }
+
+        // The service that was polled ready handles the request; the clone is left behind.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let bytes = match Limited::new(body, max_bytes).collect().await {
+                Ok(collected) => collected.to_bytes(),
+                Err(error) if error.is::<LengthLimitError>() => {
+                    return Ok(ApiError::PayloadTooLarge(None).into_response())
+                }
+                Err(_) => return Ok(ApiError::BadRequest(None).into_response()),
+            };
+
+            inner
+                .call(Request::from_parts(parts, Body::from(bytes)))
+                .await
+        })
+    }
+}
@@ -0,0 +1,131 @@
+use axum::body::Body;
+use axum::http::Request;
+use axum::response::Response;
+use futures_util::StreamExt;
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// Layer logging the request body of requests answered with a 4xx status, to debug client
+/// integrations.
+///
+/// The body is passed through to the handler as a stream while its first `max_bytes` are
+/// copied aside, so memory use stays bounded however large the body is. When the response is
+/// a client error, the captured prefix is logged as a `debug!` event with the `status`, the
+/// `body` (lossily decoded as UTF-8) and whether it was `truncated`. Only what the handler
+/// read is captured; a request rejected before its body was read logs an empty body.
+///
+/// # Examples
+///
+/// ```
+/// use axum::routing::post;
+/// use axum::Router;
+/// use skyak_axum_core::middleware::LogClientErrorBody;
+///
+/// let app: Router = Router::new()
+///     .route("/", post(|body: String| async move { body }))
+///     .layer(LogClientErrorBody::new().max_bytes(1024));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LogClientErrorBody {
+    max_bytes: usize,
+}
+
+impl LogClientErrorBody {
+    /// Creates a layer logging up to 4 KiB of each rejected body.
+    pub fn new() -> Self {
+        Self { max_bytes: 4096 }
+    }
+
+    /// Sets how many bytes of the body are captured and logged.
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+}
+
+impl Default for LogClientErrorBody {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for LogClientErrorBody {
+    type Service = LogClientErrorBodyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        LogClientErrorBodyService {
+            inner,
+            max_bytes: self.max_bytes,
+        }
+    }
+}
+
+/// Service produced by [`LogClientErrorBody`].
+#[derive(Debug, Clone)]
+pub struct LogClientErrorBodyService<S> {
+    inner: S,
+    max_bytes: usize,
+}
+
+/// Prefix of the request body seen so far.
+#[derive(Default)]
+struct Captured {
+    bytes: Vec<u8>,
+    truncated: bool,
+}
+
+impl<S> Service<Request<Body>> for LogClientErrorBodyService<S>
+where
+    S: Service<Request<Body>, Response = Response, Error = Infallible> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let max_bytes = self.max_bytes;
+        let captured = Arc::new(Mutex::new(Captured::default()));
+
+        let (parts, body) = request.into_parts();
+        let sink = captured.clone();
+        let body = body.into_data_stream().map(move |chunk| {
+            if let Ok(chunk) = &chunk {
+                let mut captured = sink.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                let room = max_bytes.saturating_sub(captured.bytes.len());
+                captured
+                    .bytes
+                    .extend_from_slice(&chunk[..chunk.len().min(room)]);
+                captured.truncated |= chunk.len() > room;
+            }
+            chunk
+        });
+        let future = self
+            .inner
+            .call(Request::from_parts(parts, Body::from_stream(body)));
+
+        Box::pin(async move {
+            let response = future.await?;
+            if response.status().is_client_error() {
+                let captured = captured
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+                tracing::debug!(
+                    status = response.status().as_u16(),
+                    body = %String::from_utf8_lossy(&captured.bytes),
+                    truncated = captured.truncated,
+                    "request body of rejected request"
+                );
+            }
+            Ok(response)
+        })
+    }
+}
@@ -0,0 +1,17 @@
+mod catch_panic;
+mod default_messages;
+mod limit_body;
+#[cfg(feature = "tracing")]
+mod log_body;
+mod require_json;
+mod response_time;
+mod timeout;
+
+pub use catch_panic::{CatchPanic, CatchPanicService};
+pub use default_messages::{DefaultMessages, DefaultMessagesService};
+pub use limit_body::{limit_body, LimitBody, LimitBodyService};
+#[cfg(feature = "tracing")]
+pub use log_body::{LogClientErrorBody, LogClientErrorBodyService};
+pub use require_json::{require_json_accept, RequireJsonAccept, RequireJsonAcceptService};
+pub use response_time::{ResponseTime, ResponseTimeService};
+pub use timeout::{ApiTimeout, ApiTimeoutService};
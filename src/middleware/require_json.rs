@@ -0,0 +1,77 @@
+use crate::errors::ApiError;
+use crate::headers::accept_quality;
+use axum::http::{header, Request};
+use axum::response::{IntoResponse, Response};
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// Layer rejecting requests whose `Accept` header rules out JSON with the standard JSON
+/// `406 Not Acceptable` error, before the handler runs.
+///
+/// Wildcards (`*/*`, `application/*`) count as accepting JSON, as does a missing `Accept`
+/// header.
+///
+/// # Examples
+///
+/// ```
+/// use axum::routing::get;
+/// use axum::Router;
+/// use skyak_axum_core::middleware::require_json_accept;
+///
+/// let app: Router = Router::new()
+///     .route("/", get(|| async { "ok" }))
+///     .layer(require_json_accept());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequireJsonAccept;
+
+/// Creates a [`RequireJsonAccept`] layer.
+pub fn require_json_accept() -> RequireJsonAccept {
+    RequireJsonAccept
+}
+
+impl<S> Layer<S> for RequireJsonAccept {
+    type Service = RequireJsonAcceptService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequireJsonAcceptService { inner }
+    }
+}
+
+/// Service produced by [`RequireJsonAccept`].
+#[derive(Debug, Clone)]
+pub struct RequireJsonAcceptService<S> {
+    inner: S,
+}
+
+impl<S, B> Service<Request<B>> for RequireJsonAcceptService<S>
+where
+    S: Service<Request<B>, Response = Response, Error = Infallible> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        let accept = request
+            .headers()
+            .get_all(header::ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .collect::<Vec<_>>()
+            .join(",");
+        if accept_quality(&accept, "application/json") <= 0.0 {
+            return Box::pin(async { Ok(ApiError::NotAcceptable(None).into_response()) });
+        }
+
+        Box::pin(self.inner.call(request))
+    }
+}
@@ -0,0 +1,81 @@
+use axum::http::{HeaderName, HeaderValue, Request};
+use axum::response::Response;
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Instant;
+use tower::{Layer, Service};
+
+/// Header carrying the handler duration in whole milliseconds.
+const X_RESPONSE_TIME_MS: HeaderName = HeaderName::from_static("x-response-time-ms");
+
+/// Layer adding an `X-Response-Time-Ms` header with the time the inner service took to
+/// produce the response.
+///
+/// The header is added to every response, including those rendered from an `ApiError`, since
+/// the error conversion itself has no notion of timing. Time spent streaming the body is not
+/// included.
+///
+/// # Examples
+///
+/// ```
+/// use axum::routing::get;
+/// use axum::Router;
+/// use skyak_axum_core::middleware::ResponseTime;
+///
+/// let app: Router = Router::new()
+///     .route("/", get(|| async { "ok" }))
+///     .layer(ResponseTime::new());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResponseTime;
+
+impl ResponseTime {
+    /// Creates a layer timing every response.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for ResponseTime {
+    type Service = ResponseTimeService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ResponseTimeService { inner }
+    }
+}
+
+/// Service produced by [`ResponseTime`].
+#[derive(Debug, Clone)]
+pub struct ResponseTimeService<S> {
+    inner: S,
+}
+
+impl<S, B> Service<Request<B>> for ResponseTimeService<S>
+where
+    S: Service<Request<B>, Response = Response, Error = Infallible> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        let start = Instant::now();
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            let mut response = future.await?;
+            let elapsed = start.elapsed().as_millis();
+            response
+                .headers_mut()
+                .insert(X_RESPONSE_TIME_MS, HeaderValue::from(elapsed as u64));
+            Ok(response)
+        })
+    }
+}
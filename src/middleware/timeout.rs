@@ -0,0 +1,82 @@
+use crate::errors::ApiError;
+use axum::http::Request;
+use axum::response::{IntoResponse, Response};
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tower::{Layer, Service};
+
+/// Layer answering requests that take longer than a fixed duration with the standard JSON
+/// `504 Gateway Timeout` error.
+///
+/// The inner future is dropped when the timeout elapses, cancelling the handler.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use axum::routing::get;
+/// use axum::Router;
+/// use skyak_axum_core::middleware::ApiTimeout;
+///
+/// let app: Router = Router::new()
+///     .route("/", get(|| async { "ok" }))
+///     .layer(ApiTimeout::new(Duration::from_secs(30)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ApiTimeout {
+    timeout: Duration,
+}
+
+impl ApiTimeout {
+    /// Creates a layer that times requests out after `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout }
+    }
+}
+
+impl<S> Layer<S> for ApiTimeout {
+    type Service = ApiTimeoutService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ApiTimeoutService {
+            inner,
+            timeout: self.timeout,
+        }
+    }
+}
+
+/// Service produced by [`ApiTimeout`].
+#[derive(Debug, Clone)]
+pub struct ApiTimeoutService<S> {
+    inner: S,
+    timeout: Duration,
+}
+
+impl<S, B> Service<Request<B>> for ApiTimeoutService<S>
+where
+    S: Service<Request<B>, Response = Response, Error = Infallible> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        let future = self.inner.call(request);
+        let timeout = self.timeout;
+
+        Box::pin(async move {
+            match tokio::time::timeout(timeout, future).await {
+                Ok(response) => response,
+                Err(_) => Ok(ApiError::GatewayTimeout(None).into_response()),
+            }
+        })
+    }
+}
@@ -0,0 +1,105 @@
+//! Assertions for testing handlers that return `ApiError`s and JSON responses.
+//!
+//! Each helper consumes the `Response`, reads the body and panics with a descriptive message
+//! when the response doesn't match, returning the decoded body for further assertions.
+
+use crate::errors::{self, ApiError, ApiErrorBody};
+use axum::body::to_bytes;
+use axum::http::{header, StatusCode};
+use axum::response::Response;
+use serde::de::DeserializeOwned;
+
+/// Asserts that `response` is an `ApiError` with the given status and message, returning its
+/// body.
+///
+/// A body without a `message` matches the default message of its error, e.g. `Not Found`.
+/// The body is read with the [configured](crate::errors::configure) keys.
+///
+/// # Panics
+///
+/// Panics when the status or message differ, or the body isn't an [`ApiErrorBody`].
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::StatusCode;
+/// use axum::response::IntoResponse;
+/// use skyak_axum_core::errors::ApiError;
+/// use skyak_axum_core::testing::assert_error;
+///
+/// # tokio_test();
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn tokio_test() {
+/// let response = ApiError::not_found("User not found").into_response();
+/// let body = assert_error(response, StatusCode::NOT_FOUND, "User not found").await;
+/// assert_eq!(body.error, "not_found");
+/// # }
+/// ```
+pub async fn assert_error(response: Response, status: StatusCode, message: &str) -> ApiErrorBody {
+    assert_eq!(response.status(), status, "unexpected status");
+    let body = read_with(response, errors::parse_body).await;
+    let actual = match &body.message {
+        Some(message) => message.to_string(),
+        None => ApiError::from_body(status, body.clone())
+            .default_message()
+            .to_string(),
+    };
+    assert_eq!(actual, message, "unexpected error message");
+    body
+}
+
+/// Asserts that `response` is a successful JSON response and deserializes its body as `T`.
+///
+/// # Panics
+///
+/// Panics when the status isn't 2xx, the `Content-Type` isn't `application/json`, or the body
+/// doesn't deserialize as `T`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::IntoResponse;
+/// use skyak_axum_core::https::success;
+/// use skyak_axum_core::testing::assert_success_json;
+///
+/// # tokio_test();
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn tokio_test() {
+/// let response = success(vec![1, 2, 3]).into_response();
+/// let numbers: Vec<u32> = assert_success_json(response).await;
+/// assert_eq!(numbers, [1, 2, 3]);
+/// # }
+/// ```
+pub async fn assert_success_json<T: DeserializeOwned>(response: Response) -> T {
+    assert!(
+        response.status().is_success(),
+        "expected a successful response, got {}",
+        response.status()
+    );
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok());
+    assert_eq!(
+        content_type,
+        Some("application/json"),
+        "expected a JSON response"
+    );
+    read_json(response).await
+}
+
+async fn read_json<T: DeserializeOwned>(response: Response) -> T {
+    read_with(response, |body| serde_json::from_slice(body)).await
+}
+
+async fn read_with<T>(response: Response, parse: fn(&[u8]) -> serde_json::Result<T>) -> T {
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("failed to read response body");
+    parse(&body).unwrap_or_else(|error| {
+        panic!(
+            "failed to deserialize response body {:?}: {error}",
+            String::from_utf8_lossy(&body)
+        )
+    })
+}
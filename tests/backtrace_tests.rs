@@ -0,0 +1,33 @@
+#![cfg(feature = "dev-backtrace")]
+
+use axum::body::to_bytes;
+use axum::response::IntoResponse;
+use serde_json::Value;
+use skyak_axum_core::errors::ApiError;
+
+async fn body_json(error: ApiError) -> Value {
+    let body = to_bytes(error.into_response().into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_backtrace_rendered_for_dedicated_constructor() {
+    let body = body_json(ApiError::internal_server_error_with_backtrace(
+        "Cache corrupted",
+    ))
+    .await;
+
+    assert_eq!(body["error"], "internal_server_error");
+    assert_eq!(body["message"], "Cache corrupted");
+    let backtrace = body["backtrace"].as_str().expect("missing backtrace");
+    assert!(!backtrace.is_empty());
+}
+
+#[tokio::test]
+async fn test_backtrace_not_rendered_for_other_errors() {
+    let body = body_json(ApiError::internal_server_error("Cache corrupted")).await;
+
+    assert!(body.get("backtrace").is_none());
+}
@@ -0,0 +1,86 @@
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use skyak_axum_core::errors::{ApiError, ApiErrorBody, RateLimit};
+use std::time::Duration;
+
+fn round_trip(error: &ApiError) -> ApiError {
+    let json = serde_json::to_string(&error.to_body()).unwrap();
+    let body: ApiErrorBody = serde_json::from_str(&json).unwrap();
+    ApiError::from_body(error.status_code(), body)
+}
+
+#[test]
+fn test_from_body_round_trips() {
+    let cases = vec![
+        ApiError::NotFound(None),
+        ApiError::bad_request("Missing name"),
+        ApiError::conflict("Email taken").with_conflicting_field("email"),
+        ApiError::UnprocessableEntity {
+            message: None,
+            errors: vec![("email".to_string(), "invalid".to_string())],
+        },
+        ApiError::TooManyRequests {
+            message: None,
+            retry_after: None,
+            rate_limit: Some(RateLimit {
+                limit: 100,
+                remaining: 0,
+                reset: 30,
+            }),
+        }
+        .with_request_id("req-1"),
+        ApiError::validation(vec![(
+            "/items/0/price".to_string(),
+            "must be positive".to_string(),
+        )]),
+        ApiError::Other(418, Some("I'm a teapot".to_string())),
+        ApiError::multiple([ApiError::not_found("No user"), ApiError::Gone(None)]),
+    ];
+
+    for error in cases {
+        assert_eq!(round_trip(&error), error);
+    }
+}
+
+#[tokio::test]
+async fn test_from_response() {
+    let cases = vec![
+        ApiError::service_unavailable("Down for maintenance")
+            .with_retry_after(Duration::from_secs(120))
+            .with_request_id("req-7"),
+        ApiError::unauthorized("Token expired").with_challenge(r#"Bearer realm="api""#),
+        ApiError::unauthorized("Token expired"),
+    ];
+
+    for error in cases {
+        let response = error.clone().into_response();
+        assert_eq!(ApiError::from_response(response).await, error);
+    }
+}
+
+#[tokio::test]
+async fn test_from_response_without_message() {
+    let response = ApiError::Conflict {
+        message: None,
+        conflicting_field: Some("email".to_string()),
+    }
+    .into_response();
+
+    assert_eq!(
+        ApiError::from_response(response).await,
+        ApiError::Conflict {
+            message: None,
+            conflicting_field: Some("email".to_string()),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_from_response_without_error_body() {
+    let response = (StatusCode::BAD_GATEWAY, "upstream exploded").into_response();
+
+    assert_eq!(
+        ApiError::from_response(response).await,
+        ApiError::BadGateway(None)
+    );
+}
@@ -0,0 +1,252 @@
+//! The error body configuration is process-wide, so these tests live in their own binary and
+//! serialize on `CONFIG` to avoid observing each other's setting. Responses are rendered
+//! while the lock is held; only reading the bodies happens afterwards.
+
+use axum::body::{to_bytes, Body};
+use axum::http::Request;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use serde_json::{json, Value};
+use skyak_axum_core::errors::{
+    self, ApiError, ApiErrorBody, ApiErrorConfig, FieldErrors, InvalidConfig, RateLimit,
+};
+use skyak_axum_core::middleware::DefaultMessages;
+use std::sync::Mutex;
+use tower::ServiceExt;
+
+static CONFIG: Mutex<()> = Mutex::new(());
+
+fn custom() -> ApiErrorConfig {
+    ApiErrorConfig::new().code_key("code").message_key("msg")
+}
+
+async fn body(response: Response) -> Value {
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let mut body: Value = serde_json::from_slice(&body).unwrap();
+    // Added by the `timestamps` feature.
+    body.as_object_mut().unwrap().remove("timestamp");
+    body
+}
+
+#[tokio::test]
+async fn test_default_and_custom_keys() {
+    let (default, customized) = {
+        let _guard = CONFIG.lock().unwrap();
+        let default = ApiError::not_found("User not found").into_response();
+        errors::configure(custom()).unwrap();
+        let customized = ApiError::not_found("User not found").into_response();
+        errors::configure(ApiErrorConfig::default()).unwrap();
+        (default, customized)
+    };
+
+    assert_eq!(
+        body(default).await,
+        json!({ "error": "not_found", "status": 404, "message": "User not found" })
+    );
+    assert_eq!(
+        body(customized).await,
+        json!({ "code": "not_found", "status": 404, "msg": "User not found" })
+    );
+}
+
+#[tokio::test]
+async fn test_to_body_keeps_default_keys() {
+    let json = {
+        let _guard = CONFIG.lock().unwrap();
+        errors::configure(custom()).unwrap();
+        let json = serde_json::to_value(ApiError::not_found("User not found").to_body()).unwrap();
+        errors::configure(ApiErrorConfig::default()).unwrap();
+        json
+    };
+
+    assert_eq!(json["error"], "not_found");
+    assert_eq!(json["message"], "User not found");
+}
+
+#[test]
+fn test_default_messages_layer_uses_configured_key() {
+    let app = Router::new()
+        .route("/", get(|| async { ApiError::NotFound(None) }))
+        .layer(DefaultMessages::new().message("not_found", "Nothing here"));
+    let request = Request::builder().uri("/").body(Body::empty()).unwrap();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    // The layer reads the configuration while the response future runs.
+    let body = {
+        let _guard = CONFIG.lock().unwrap();
+        errors::configure(custom()).unwrap();
+        let body = runtime.block_on(async { body(app.oneshot(request).await.unwrap()).await });
+        errors::configure(ApiErrorConfig::default()).unwrap();
+        body
+    };
+
+    assert_eq!(body["msg"], "Nothing here");
+    assert!(body.get("message").is_none());
+}
+
+#[test]
+fn test_from_response_reads_configured_keys() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let error = ApiError::multiple([
+        ApiError::not_found("User not found"),
+        ApiError::conflict("Email taken").with_conflicting_field("email"),
+    ]);
+
+    // The body is parsed with the configuration installed while the future runs.
+    let parsed = {
+        let _guard = CONFIG.lock().unwrap();
+        errors::configure(custom()).unwrap();
+        let parsed = runtime.block_on(ApiError::from_response(error.clone().into_response()));
+        errors::configure(ApiErrorConfig::default()).unwrap();
+        parsed
+    };
+
+    assert_eq!(parsed, error);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_assert_error_reads_configured_keys() {
+    use axum::http::StatusCode;
+    use skyak_axum_core::testing::assert_error;
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    let body = {
+        let _guard = CONFIG.lock().unwrap();
+        errors::configure(custom()).unwrap();
+        let response = ApiError::not_found("User not found").into_response();
+        let body = runtime.block_on(assert_error(
+            response,
+            StatusCode::NOT_FOUND,
+            "User not found",
+        ));
+        errors::configure(ApiErrorConfig::default()).unwrap();
+        body
+    };
+
+    assert_eq!(body.error, "not_found");
+}
+
+#[tokio::test]
+async fn test_configure_rejects_colliding_keys() {
+    let response = {
+        let _guard = CONFIG.lock().unwrap();
+        for (config, key) in [
+            (ApiErrorConfig::new().message_key("status"), "status"),
+            (ApiErrorConfig::new().code_key("details"), "details"),
+            (
+                ApiErrorConfig::new().code_key("msg").message_key("msg"),
+                "msg",
+            ),
+        ] {
+            assert_eq!(errors::configure(config), Err(InvalidConfig(key.into())));
+        }
+        ApiError::not_found("User not found").into_response()
+    };
+
+    // The installed configuration is left in place.
+    assert_eq!(
+        body(response).await,
+        json!({ "error": "not_found", "status": 404, "message": "User not found" })
+    );
+}
+
+/// Renames the custom keys of a rendered body, and of its `details`, back to the defaults.
+fn default_keys(mut json: Value) -> Value {
+    let fields = json.as_object_mut().unwrap();
+    if let Some(code) = fields.remove("code") {
+        fields.insert("error".to_string(), code);
+    }
+    if let Some(message) = fields.remove("msg") {
+        fields.insert("message".to_string(), message);
+    }
+    if let Some(Value::Array(details)) = fields.remove("details") {
+        let details = details.into_iter().map(default_keys).collect();
+        fields.insert("details".to_string(), Value::Array(details));
+    }
+    json
+}
+
+#[tokio::test]
+async fn test_configured_render_keeps_every_field() {
+    // Listing every field makes this test fail to compile when `ApiErrorBody` grows one.
+    let full = ApiErrorBody {
+        error: "conflict".into(),
+        status: 409,
+        field: Some("email".to_string()),
+        message: Some("Email taken".into()),
+        errors: FieldErrors::Fields([("email".to_string(), "taken".to_string())].into()),
+        rate_limit: Some(RateLimit {
+            limit: 100,
+            remaining: 0,
+            reset: 30,
+        }),
+        details: vec![ApiError::NotFound(None).to_body()],
+        request_id: Some("req-1".to_string()),
+        backtrace: Some("backtrace".to_string()),
+        timestamp: Some("2026-01-01T00:00:00Z".to_string()),
+    };
+    let mut expected: Vec<String> = serde_json::to_value(&full)
+        .unwrap()
+        .as_object()
+        .unwrap()
+        .keys()
+        .cloned()
+        .collect();
+    if !cfg!(feature = "dev-backtrace") {
+        expected.retain(|key| key != "backtrace");
+    }
+    if !cfg!(feature = "timestamps") {
+        expected.retain(|key| key != "timestamp");
+    }
+
+    let cases = vec![
+        ApiError::conflict("Email taken")
+            .with_conflicting_field("email")
+            .with_request_id("req-1"),
+        ApiError::UnprocessableEntity {
+            message: None,
+            errors: vec![("email".to_string(), "invalid".to_string())],
+        },
+        ApiError::rate_limited(100, 0, std::time::Duration::from_secs(30)),
+        ApiError::multiple([ApiError::not_found("No user"), ApiError::Gone(None)]),
+        ApiError::internal_server_error_with_backtrace("Cache corrupted"),
+    ];
+    let rendered: Vec<_> = {
+        let _guard = CONFIG.lock().unwrap();
+        errors::configure(custom()).unwrap();
+        let rendered = cases
+            .into_iter()
+            .map(|error| (error.to_body(), error.into_response()))
+            .collect();
+        errors::configure(ApiErrorConfig::default()).unwrap();
+        rendered
+    };
+
+    let mut seen = Vec::new();
+    for (expected_body, response) in rendered {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let mut actual = default_keys(serde_json::from_slice(&bytes).unwrap());
+        seen.extend(actual.as_object().unwrap().keys().cloned());
+
+        // Rendered a moment apart, so timestamps may differ.
+        let mut expected_body = serde_json::to_value(expected_body).unwrap();
+        actual.as_object_mut().unwrap().remove("timestamp");
+        expected_body.as_object_mut().unwrap().remove("timestamp");
+        assert_eq!(actual, expected_body);
+    }
+    for key in expected {
+        assert!(
+            seen.contains(&key),
+            "`{key}` missing from configured bodies"
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use axum::http::header;
+use axum::response::IntoResponse;
+use skyak_axum_core::cookies::{CookieBuilder, SameSite};
+use skyak_axum_core::errors::ApiError;
+use skyak_axum_core::https;
+use std::time::Duration;
+
+#[test]
+fn test_session_cookie() {
+    let cookie = CookieBuilder::new("session", "abc123")
+        .http_only()
+        .secure()
+        .same_site(SameSite::Strict)
+        .max_age(Duration::from_secs(3600))
+        .path("/");
+
+    assert_eq!(
+        cookie.header_value().unwrap(),
+        "session=abc123; Max-Age=3600; Path=/; Secure; HttpOnly; SameSite=Strict"
+    );
+}
+
+#[test]
+fn test_same_site_rendering() {
+    let render = |same_site| {
+        CookieBuilder::new("id", "1")
+            .same_site(same_site)
+            .header_value()
+            .unwrap()
+    };
+
+    assert_eq!(render(SameSite::Strict), "id=1; SameSite=Strict");
+    assert_eq!(render(SameSite::Lax), "id=1; SameSite=Lax");
+    assert_eq!(render(SameSite::None), "id=1; SameSite=None");
+}
+
+#[test]
+fn test_value_is_escaped() {
+    let cookie = CookieBuilder::new("prefs", "a b;c=\"d\",100%");
+
+    assert_eq!(
+        cookie.header_value().unwrap(),
+        "prefs=a%20b%3Bc=%22d%22%2C100%25"
+    );
+}
+
+#[test]
+fn test_invalid_name_is_rejected() {
+    let error = CookieBuilder::new("bad name", "x")
+        .header_value()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid cookie: cookie name must be a non-empty token"
+    );
+}
+
+#[test]
+fn test_invalid_path_is_rejected() {
+    assert!(CookieBuilder::new("id", "1")
+        .path("/; Domain=evil.com")
+        .header_value()
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_success_with_cookie() {
+    let cookie = CookieBuilder::new("session", "abc123").http_only();
+    let response = https::success_with_cookie("Welcome".to_string(), cookie).into_response();
+
+    assert_eq!(
+        response.headers()[header::SET_COOKIE],
+        "session=abc123; HttpOnly"
+    );
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+}
+
+#[tokio::test]
+async fn test_success_with_invalid_cookie() {
+    let cookie = CookieBuilder::new("", "abc123");
+
+    assert!(matches!(
+        https::success_with_cookie("Welcome".to_string(), cookie),
+        Err(ApiError::InternalServerError(Some(_)))
+    ));
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "derive")]
+
+use axum::body::to_bytes;
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use serde_json::{json, Value};
+use skyak_axum_core::errors::{ApiError, IntoApiError};
+use std::fmt;
+
+#[derive(Debug, IntoApiError)]
+enum AccountError {
+    #[status(404)]
+    Missing(u64),
+    #[status(409)]
+    Duplicate {
+        email: String,
+    },
+    #[status(418)]
+    Teapot,
+    Storage,
+}
+
+impl fmt::Display for AccountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccountError::Missing(id) => write!(f, "account {id} not found"),
+            AccountError::Duplicate { email } => write!(f, "{email} is already registered"),
+            AccountError::Teapot => write!(f, "no coffee here"),
+            AccountError::Storage => write!(f, "storage failure"),
+        }
+    }
+}
+
+#[derive(Debug, IntoApiError)]
+#[status(403)]
+struct Suspended;
+
+impl fmt::Display for Suspended {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "account suspended")
+    }
+}
+
+#[derive(Debug, IntoApiError)]
+#[status(401)]
+struct SessionExpired;
+
+impl fmt::Display for SessionExpired {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "session expired")
+    }
+}
+
+async fn body_json(response: axum::response::Response) -> Value {
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let mut body: Value = serde_json::from_slice(&body).unwrap();
+    // Added by the `timestamps` feature.
+    if let Some(fields) = body.as_object_mut() {
+        fields.remove("timestamp");
+    }
+    body
+}
+
+#[test]
+fn test_variant_statuses() {
+    assert_eq!(
+        AccountError::Missing(7).to_api_error(),
+        ApiError::not_found("account 7 not found")
+    );
+    assert_eq!(
+        AccountError::Duplicate {
+            email: "a@b.c".to_string()
+        }
+        .to_api_error(),
+        ApiError::conflict("a@b.c is already registered")
+    );
+    assert_eq!(
+        AccountError::Teapot.to_api_error(),
+        ApiError::Other(418, Some("no coffee here".to_string()))
+    );
+    assert_eq!(
+        AccountError::Storage.to_api_error(),
+        ApiError::internal_server_error("storage failure")
+    );
+    assert_eq!(
+        Suspended.to_api_error(),
+        ApiError::forbidden("account suspended")
+    );
+}
+
+#[tokio::test]
+async fn test_derived_into_response() {
+    let response = AccountError::Missing(7).into_response();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        body_json(response).await,
+        json!({ "error": "not_found", "status": 404, "message": "account 7 not found" })
+    );
+}
+
+#[tokio::test]
+async fn test_derived_unauthorized_sends_challenge() {
+    let response = SessionExpired.into_response();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+    assert_eq!(body_json(response).await["error"], "unauthorized");
+}
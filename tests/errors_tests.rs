@@ -0,0 +1,1188 @@
+use axum::body::to_bytes;
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use serde_json::{json, Value};
+use skyak_axum_core::errors::{ApiError, ApiErrorBody, ErrorAggregator, InvalidStatus, RateLimit};
+use std::time::Duration;
+
+/// Parses a JSON body, dropping the `timestamp` added by the `timestamps` feature so
+/// assertions can compare whole bodies.
+fn parse_json(body: &[u8]) -> Value {
+    let mut body: Value = serde_json::from_slice(body).unwrap();
+    if let Some(fields) = body.as_object_mut() {
+        fields.remove("timestamp");
+    }
+    body
+}
+
+async fn render(error: ApiError) -> (StatusCode, String, Value) {
+    let response = error.into_response();
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .expect("missing content type")
+        .to_str()
+        .unwrap()
+        .to_string();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, content_type, parse_json(&body))
+}
+
+#[tokio::test]
+async fn test_json_body_for_each_variant() {
+    let cases = vec![
+        (
+            ApiError::BadRequest(None),
+            StatusCode::BAD_REQUEST,
+            "bad_request",
+            "Bad Request",
+        ),
+        (
+            ApiError::NotFound(None),
+            StatusCode::NOT_FOUND,
+            "not_found",
+            "Not Found",
+        ),
+        (
+            ApiError::InternalServerError(None),
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "internal_server_error",
+            "Internal Server Error",
+        ),
+        (
+            ApiError::Unauthorized {
+                message: None,
+                challenge: None,
+            },
+            StatusCode::UNAUTHORIZED,
+            "unauthorized",
+            "Unauthorized",
+        ),
+        (
+            ApiError::Forbidden(None),
+            StatusCode::FORBIDDEN,
+            "forbidden",
+            "Forbidden",
+        ),
+        (
+            ApiError::Conflict {
+                message: None,
+                conflicting_field: None,
+            },
+            StatusCode::CONFLICT,
+            "conflict",
+            "Conflict",
+        ),
+        (
+            ApiError::TooManyRequests {
+                message: None,
+                retry_after: None,
+                rate_limit: None,
+            },
+            StatusCode::TOO_MANY_REQUESTS,
+            "rate_limited",
+            "Too Many Requests",
+        ),
+        (
+            ApiError::ServiceUnavailable {
+                message: None,
+                retry_after: None,
+            },
+            StatusCode::SERVICE_UNAVAILABLE,
+            "service_unavailable",
+            "Service Unavailable",
+        ),
+        (
+            ApiError::GatewayTimeout(None),
+            StatusCode::GATEWAY_TIMEOUT,
+            "gateway_timeout",
+            "Gateway Timeout",
+        ),
+        (
+            ApiError::Other(418, None),
+            StatusCode::IM_A_TEAPOT,
+            "http_418",
+            "Other Error",
+        ),
+    ];
+
+    for (error, expected_status, expected_error, expected_message) in cases {
+        assert_eq!(error.default_message(), expected_message);
+        let (status, content_type, body) = render(error).await;
+        assert_eq!(status, expected_status);
+        assert_eq!(content_type, "application/json");
+        assert_eq!(
+            body,
+            json!({ "error": expected_error, "status": expected_status.as_u16() })
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_json_body_with_custom_message() {
+    let (status, content_type, body) = render(ApiError::NotFound(Some(
+        "User profile not found".to_string(),
+    )))
+    .await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(content_type, "application/json");
+    assert_eq!(
+        body,
+        json!({ "error": "not_found", "status": 404, "message": "User profile not found" })
+    );
+}
+
+#[tokio::test]
+async fn test_other_with_invalid_status_falls_back_to_500() {
+    let (status, _, body) = render(ApiError::Other(42, None)).await;
+
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(body["error"], "http_500");
+}
+
+#[tokio::test]
+async fn test_other_with_non_error_status_falls_back_to_500() {
+    for code in [200, 999] {
+        let error = ApiError::Other(code, None);
+        assert_eq!(error.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(error.is_server_error());
+        assert!(!error.is_client_error());
+
+        let (status, _, body) = render(error).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body["error"], "http_500");
+    }
+}
+
+#[tokio::test]
+async fn test_bad_request_keeps_custom_message() {
+    let (status, _, body) = render(ApiError::BadRequest(Some("email is required".into()))).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["message"], "email is required");
+}
+
+#[test]
+fn test_display_with_custom_message() {
+    let error = ApiError::NotFound(Some("User not found".to_string()));
+    assert_eq!(format!("{}", error), "NotFound: User not found");
+}
+
+#[test]
+fn test_display_with_default_message() {
+    assert_eq!(
+        format!(
+            "{}",
+            ApiError::Unauthorized {
+                message: None,
+                challenge: None
+            }
+        ),
+        "Unauthorized: Unauthorized"
+    );
+    assert_eq!(
+        format!("{}", ApiError::Other(418, Some("I'm a teapot".to_string()))),
+        "Other: I'm a teapot"
+    );
+}
+
+#[test]
+fn test_debug_prints_variant() {
+    let error = ApiError::BadRequest(Some("email is required".to_string()));
+    assert_eq!(
+        format!("{:?}", error),
+        "BadRequest(Some(\"email is required\"))"
+    );
+    assert_eq!(
+        format!("{:?}", ApiError::Other(418, None)),
+        "Other(418, None)"
+    );
+}
+
+#[test]
+fn test_usable_as_std_error() {
+    let error: Box<dyn std::error::Error> = Box::new(ApiError::Forbidden(None));
+    assert!(error.source().is_none());
+    assert_eq!(error.to_string(), "Forbidden: Forbidden");
+}
+
+#[tokio::test]
+async fn test_from_serde_json_error() {
+    let parse_error = serde_json::from_str::<Value>("{\"name\": ").unwrap_err();
+    let expected = format!("invalid JSON: {parse_error}");
+    let error = ApiError::from(parse_error);
+
+    match &error {
+        ApiError::BadRequest(Some(message)) => assert_eq!(message, &expected),
+        _ => panic!("Expected BadRequest error variant"),
+    }
+
+    let (status, _, body) = render(error).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["message"], expected);
+}
+
+#[test]
+fn test_from_io_error() {
+    use std::io::{Error, ErrorKind};
+
+    let cases = [
+        (
+            ErrorKind::NotFound,
+            ApiError::NotFound(Some("missing".into())),
+        ),
+        (
+            ErrorKind::PermissionDenied,
+            ApiError::Forbidden(Some("missing".into())),
+        ),
+        (
+            ErrorKind::TimedOut,
+            ApiError::GatewayTimeout(Some("missing".into())),
+        ),
+        (
+            ErrorKind::BrokenPipe,
+            ApiError::InternalServerError(Some("missing".into())),
+        ),
+        (
+            ErrorKind::Other,
+            ApiError::InternalServerError(Some("missing".into())),
+        ),
+    ];
+
+    for (kind, expected) in cases {
+        assert_eq!(ApiError::from(Error::new(kind, "missing")), expected);
+    }
+}
+
+#[tokio::test]
+async fn test_additional_client_error_variants() {
+    let cases = vec![
+        (
+            ApiError::MethodNotAllowed(None),
+            StatusCode::METHOD_NOT_ALLOWED,
+            "method_not_allowed",
+            "Method Not Allowed",
+        ),
+        (
+            ApiError::NotAcceptable(None),
+            StatusCode::NOT_ACCEPTABLE,
+            "not_acceptable",
+            "Not Acceptable",
+        ),
+        (ApiError::Gone(None), StatusCode::GONE, "gone", "Gone"),
+        (
+            ApiError::PayloadTooLarge(None),
+            StatusCode::PAYLOAD_TOO_LARGE,
+            "payload_too_large",
+            "Payload Too Large",
+        ),
+        (
+            ApiError::UnprocessableEntity {
+                message: None,
+                errors: vec![],
+            },
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "unprocessable_entity",
+            "Unprocessable Entity",
+        ),
+    ];
+
+    for (error, expected_status, expected_error, expected_message) in cases {
+        assert_eq!(error.default_message(), expected_message);
+        let (status, _, body) = render(error).await;
+        assert_eq!(status, expected_status);
+        assert_eq!(
+            body,
+            json!({ "error": expected_error, "status": expected_status.as_u16() })
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_unprocessable_entity_with_field_errors() {
+    let error = ApiError::UnprocessableEntity {
+        message: None,
+        errors: vec![
+            ("email".to_string(), "invalid".to_string()),
+            ("age".to_string(), "must be positive".to_string()),
+        ],
+    };
+    let response = error.into_response();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body = std::str::from_utf8(&body).unwrap();
+    let expected = r#"{"error":"unprocessable_entity","status":422,"errors":{"age":"must be positive","email":"invalid"}}"#;
+    #[cfg(not(feature = "timestamps"))]
+    assert_eq!(body, expected);
+    // The `timestamps` feature appends a `timestamp` field after the others.
+    #[cfg(feature = "timestamps")]
+    assert!(body.starts_with(&expected[..expected.len() - 1]));
+}
+
+#[tokio::test]
+async fn test_unprocessable_entity_without_field_errors() {
+    let (status, _, body) = render(ApiError::UnprocessableEntity {
+        message: None,
+        errors: vec![],
+    })
+    .await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        body,
+        json!({ "error": "unprocessable_entity", "status": 422 })
+    );
+}
+
+#[tokio::test]
+async fn test_not_implemented() {
+    let (status, _, body) = render(ApiError::NotImplemented(None)).await;
+
+    assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+    assert_eq!(body, json!({ "error": "not_implemented", "status": 501 }));
+}
+
+#[tokio::test]
+async fn test_bad_gateway() {
+    let (status, _, body) = render(ApiError::BadGateway(Some("upstream down".into()))).await;
+
+    assert_eq!(status, StatusCode::BAD_GATEWAY);
+    assert_eq!(
+        body,
+        json!({ "error": "bad_gateway", "status": 502, "message": "upstream down" })
+    );
+    assert_eq!(
+        ApiError::BadGateway(None).to_string(),
+        "BadGateway: Bad Gateway"
+    );
+}
+
+#[tokio::test]
+async fn test_too_many_requests_retry_after_header() {
+    let response = ApiError::too_many_requests("Slow down")
+        .with_retry_after(Duration::from_secs(30))
+        .into_response();
+
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.headers()[header::RETRY_AFTER], "30");
+}
+
+#[tokio::test]
+async fn test_service_unavailable_retry_after_rounds_up() {
+    let response = ApiError::ServiceUnavailable {
+        message: None,
+        retry_after: Some(Duration::from_millis(1500)),
+    }
+    .into_response();
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.headers()[header::RETRY_AFTER], "2");
+}
+
+#[tokio::test]
+async fn test_no_retry_after_header_without_duration() {
+    let response = ApiError::too_many_requests("Slow down").into_response();
+
+    assert!(response.headers().get(header::RETRY_AFTER).is_none());
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = parse_json(&body);
+    assert_eq!(body["message"], "Slow down");
+}
+
+#[test]
+fn test_with_retry_after_ignores_other_variants() {
+    let error = ApiError::NotFound(None).with_retry_after(Duration::from_secs(5));
+    assert!(matches!(error, ApiError::NotFound(None)));
+}
+
+#[tokio::test]
+async fn test_unauthorized_default_challenge() {
+    let response = ApiError::unauthorized("Token expired").into_response();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+}
+
+#[tokio::test]
+async fn test_unauthorized_custom_challenge() {
+    let response = ApiError::unauthorized("Token expired")
+        .with_challenge(r#"Bearer realm="api""#)
+        .into_response();
+
+    assert_eq!(
+        response.headers()[header::WWW_AUTHENTICATE],
+        r#"Bearer realm="api""#
+    );
+}
+
+#[tokio::test]
+async fn test_problem_details_response() {
+    let response = ApiError::NotFound(Some("User not found".to_string())).into_problem_response();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/problem+json"
+    );
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = parse_json(&body);
+    assert_eq!(
+        body,
+        json!({
+            "type": "about:blank",
+            "title": "Not Found",
+            "status": 404,
+            "detail": "User not found",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_problem_details_without_custom_message() {
+    let response = ApiError::Other(418, None).into_problem_response();
+
+    assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = parse_json(&body);
+    assert_eq!(
+        body,
+        json!({ "type": "about:blank", "title": "Other Error", "status": 418 })
+    );
+}
+
+#[tokio::test]
+async fn test_problem_details_pointer_errors() {
+    let response = ApiError::validation(vec![
+        ("/items/0/price".to_string(), "must be positive".to_string()),
+        ("/items/1/price".to_string(), "must be positive".to_string()),
+        ("/customer/email".to_string(), "invalid".to_string()),
+    ])
+    .into_problem_response();
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(
+        parse_json(&body)["errors"],
+        json!([
+            { "pointer": "/items/0/price", "detail": "must be positive" },
+            { "pointer": "/items/1/price", "detail": "must be positive" },
+            { "pointer": "/customer/email", "detail": "invalid" }
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_problem_details_multiple_errors() {
+    let response = ApiError::multiple([
+        ApiError::bad_request("Quantity must be positive"),
+        ApiError::NotFound(None),
+    ])
+    .into_problem_response();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(
+        parse_json(&body),
+        json!({
+            "type": "about:blank",
+            "title": "Multiple Errors",
+            "status": 404,
+            "errors": [
+                {
+                    "type": "about:blank",
+                    "title": "Bad Request",
+                    "status": 400,
+                    "detail": "Quantity must be positive"
+                },
+                { "type": "about:blank", "title": "Not Found", "status": 404 }
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_code_for_each_variant() {
+    let cases = vec![
+        (ApiError::BadRequest(None), "bad_request"),
+        (ApiError::NotFound(None), "not_found"),
+        (ApiError::InternalServerError(None), "internal_server_error"),
+        (ApiError::unauthorized("x"), "unauthorized"),
+        (ApiError::Forbidden(None), "forbidden"),
+        (
+            ApiError::Conflict {
+                message: None,
+                conflicting_field: None,
+            },
+            "conflict",
+        ),
+        (ApiError::MethodNotAllowed(None), "method_not_allowed"),
+        (ApiError::NotAcceptable(None), "not_acceptable"),
+        (ApiError::Gone(None), "gone"),
+        (ApiError::PayloadTooLarge(None), "payload_too_large"),
+        (
+            ApiError::UnprocessableEntity {
+                message: None,
+                errors: vec![],
+            },
+            "unprocessable_entity",
+        ),
+        (ApiError::too_many_requests("x"), "rate_limited"),
+        (ApiError::NotImplemented(None), "not_implemented"),
+        (ApiError::BadGateway(None), "bad_gateway"),
+        (ApiError::service_unavailable("x"), "service_unavailable"),
+        (ApiError::GatewayTimeout(None), "gateway_timeout"),
+        (ApiError::Other(418, None), "http_418"),
+        (ApiError::Other(451, Some("x".to_string())), "http_451"),
+    ];
+
+    for (error, expected) in cases {
+        assert_eq!(error.code(), expected);
+    }
+}
+
+#[test]
+fn test_other_constructor_accepts_valid_status() {
+    let error = ApiError::other(418, Some("I'm a teapot".to_string())).unwrap();
+    assert!(matches!(error, ApiError::Other(418, Some(ref msg)) if msg == "I'm a teapot"));
+}
+
+#[test]
+fn test_other_constructor_rejects_invalid_status() {
+    let error = ApiError::other(999, None).unwrap_err();
+    assert_eq!(error, InvalidStatus(999));
+    assert_eq!(
+        error.to_string(),
+        "999 is not a valid HTTP error status (400-599)"
+    );
+    assert!(ApiError::other(200, None).is_err());
+    assert!(ApiError::other(42, None).is_err());
+}
+
+#[test]
+fn test_constructors() {
+    let user_id = 7;
+    assert!(matches!(
+        ApiError::not_found(format!("User {user_id} not found")),
+        ApiError::NotFound(Some(ref msg)) if msg == "User 7 not found"
+    ));
+    assert!(matches!(
+        ApiError::bad_request("email is required"),
+        ApiError::BadRequest(Some(ref msg)) if msg == "email is required"
+    ));
+    assert!(matches!(
+        ApiError::internal_server_error("boom"),
+        ApiError::InternalServerError(Some(ref msg)) if msg == "boom"
+    ));
+    assert!(matches!(
+        ApiError::conflict(String::from("taken")),
+        ApiError::Conflict { message: Some(ref msg), conflicting_field: None } if msg == "taken"
+    ));
+    assert!(matches!(
+        ApiError::unprocessable_entity("invalid"),
+        ApiError::UnprocessableEntity { message: Some(ref msg), ref errors }
+            if msg == "invalid" && errors.is_empty()
+    ));
+    assert!(matches!(
+        ApiError::gateway_timeout("upstream slow"),
+        ApiError::GatewayTimeout(Some(ref msg)) if msg == "upstream slow"
+    ));
+}
+
+#[tokio::test]
+async fn test_with_request_id() {
+    let response = ApiError::not_found("User not found")
+        .with_request_id("req-42")
+        .into_response();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers()["x-request-id"], "req-42");
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = parse_json(&body);
+    assert_eq!(
+        body,
+        json!({ "error": "not_found", "status": 404, "message": "User not found", "request_id": "req-42" })
+    );
+}
+
+#[tokio::test]
+async fn test_without_request_id() {
+    let response = ApiError::not_found("User not found").into_response();
+
+    assert!(response.headers().get("x-request-id").is_none());
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = parse_json(&body);
+    assert!(body.get("request_id").is_none());
+}
+
+#[tokio::test]
+async fn test_request_id_keeps_variant_headers() {
+    let error = ApiError::too_many_requests("Slow down")
+        .with_request_id("req-1")
+        .with_retry_after(Duration::from_secs(10))
+        .with_request_id("req-2");
+
+    assert!(matches!(error.inner(), ApiError::TooManyRequests { .. }));
+    assert_eq!(error.to_string(), "TooManyRequests: Slow down");
+    assert_eq!(error.code(), "rate_limited");
+
+    let response = error.into_response();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.headers()[header::RETRY_AFTER], "10");
+    assert_eq!(response.headers()["x-request-id"], "req-2");
+}
+
+async fn render_for(accept: &str) -> (String, String) {
+    let response = ApiError::not_found("User not found").into_response_for(accept);
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let content_type = response.headers()[header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (content_type, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn test_negotiation_prefers_json() {
+    let (content_type, body) = render_for("application/json, text/plain;q=0.5").await;
+
+    assert_eq!(content_type, "application/json");
+    assert_eq!(
+        parse_json(body.as_bytes()),
+        json!({ "error": "not_found", "status": 404, "message": "User not found" })
+    );
+}
+
+#[tokio::test]
+async fn test_negotiation_prefers_text() {
+    let (content_type, body) = render_for("text/plain").await;
+    assert_eq!(content_type, "text/plain; charset=utf-8");
+    assert_eq!(body, "User not found");
+
+    let (content_type, _) = render_for("text/*, application/json;q=0.1").await;
+    assert_eq!(content_type, "text/plain; charset=utf-8");
+}
+
+#[tokio::test]
+async fn test_negotiation_wildcard_uses_json() {
+    let (content_type, _) = render_for("*/*").await;
+    assert_eq!(content_type, "application/json");
+
+    let (content_type, _) = render_for("text/plain, */*;q=0.8").await;
+    assert_eq!(content_type, "text/plain; charset=utf-8");
+
+    let (content_type, _) = render_for("").await;
+    assert_eq!(content_type, "application/json");
+}
+
+#[tokio::test]
+async fn test_negotiation_falls_back_to_text() {
+    let (content_type, body) = render_for("image/png").await;
+    assert_eq!(content_type, "text/plain; charset=utf-8");
+    assert_eq!(body, "User not found");
+
+    let (content_type, _) = render_for("application/json;q=0, image/*").await;
+    assert_eq!(content_type, "text/plain; charset=utf-8");
+}
+
+#[tokio::test]
+async fn test_html_response_escapes_message() {
+    let response = ApiError::bad_request("<script>alert('x')</script> & more").into_html_response();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/html; charset=utf-8"
+    );
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let page = String::from_utf8(body.to_vec()).unwrap();
+    assert!(page.contains("<h1>400 Bad Request</h1>"));
+    assert!(page.contains("&lt;script&gt;alert(&#x27;x&#x27;)&lt;/script&gt; &amp; more"));
+    assert!(!page.contains("<script>"));
+}
+
+#[tokio::test]
+async fn test_source_is_chained_but_not_rendered() {
+    use std::error::Error;
+
+    let cause = std::io::Error::other("connection refused by db-primary:5432");
+    let error = ApiError::internal_server_error("Database unavailable").with_source(cause);
+
+    let source = error.source().expect("source should be set");
+    assert_eq!(source.to_string(), "connection refused by db-primary:5432");
+    assert!(matches!(error.inner(), ApiError::InternalServerError(_)));
+
+    let response = error.into_response();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    assert!(!body.contains("db-primary"));
+    assert_eq!(
+        parse_json(body.as_bytes()),
+        json!({ "error": "internal_server_error", "status": 500, "message": "Database unavailable" })
+    );
+}
+
+#[test]
+fn test_source_is_none_by_default() {
+    use std::error::Error;
+
+    assert!(ApiError::NotFound(None).source().is_none());
+    assert!(ApiError::NotFound(None)
+        .with_request_id("req-1")
+        .source()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_conflict_with_field() {
+    let (status, _, body) =
+        render(ApiError::conflict("Email already registered").with_conflicting_field("email"))
+            .await;
+
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(
+        body,
+        json!({ "error": "conflict", "status": 409, "field": "email", "message": "Email already registered" })
+    );
+}
+
+#[tokio::test]
+async fn test_conflict_without_field() {
+    let (_, _, body) = render(ApiError::conflict("Email already registered")).await;
+
+    assert_eq!(
+        body,
+        json!({ "error": "conflict", "status": 409, "message": "Email already registered" })
+    );
+    assert!(body.get("field").is_none());
+}
+
+#[tokio::test]
+async fn test_conflicting_field_survives_context() {
+    let (_, _, body) = render(
+        ApiError::conflict("Slug taken")
+            .with_request_id("req-7")
+            .with_conflicting_field("slug"),
+    )
+    .await;
+
+    assert_eq!(body["field"], "slug");
+    assert_eq!(body["request_id"], "req-7");
+}
+
+#[test]
+fn test_to_body_serializes_in_stable_order() {
+    let error = ApiError::UnprocessableEntity {
+        message: Some("Invalid input".to_string()),
+        errors: vec![
+            ("name".to_string(), "required".to_string()),
+            ("age".to_string(), "must be positive".to_string()),
+        ],
+    }
+    .with_request_id("req-1");
+    let mut body = error.to_body();
+    body.timestamp = None;
+
+    assert_eq!(
+        serde_json::to_string(&body).unwrap(),
+        r#"{"error":"unprocessable_entity","status":422,"message":"Invalid input","errors":{"age":"must be positive","name":"required"},"request_id":"req-1"}"#
+    );
+}
+
+#[test]
+fn test_body_round_trips() {
+    let body = ApiError::conflict("Email already registered")
+        .with_conflicting_field("email")
+        .to_body();
+    let json = serde_json::to_string(&body).unwrap();
+    let parsed: ApiErrorBody = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed, body);
+    assert_eq!(parsed.field.as_deref(), Some("email"));
+}
+
+#[test]
+fn test_body_rejects_unknown_fields() {
+    let result = serde_json::from_str::<ApiErrorBody>(
+        r#"{"error":"not_found","status":404,"message":"Not Found","extra":true}"#,
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_equal_errors() {
+    assert_eq!(
+        ApiError::not_found("x"),
+        ApiError::NotFound(Some("x".into()))
+    );
+    assert_eq!(
+        ApiError::Other(418, Some("I'm a teapot".into())),
+        ApiError::other(418, Some("I'm a teapot".into())).unwrap()
+    );
+    assert_eq!(
+        ApiError::NotFound(None).with_request_id("req-1"),
+        ApiError::NotFound(None)
+            .with_request_id("req-1")
+            .with_source("ignored in comparisons")
+    );
+}
+
+#[test]
+fn test_unequal_errors() {
+    assert_ne!(ApiError::not_found("x"), ApiError::not_found("y"));
+    assert_ne!(ApiError::NotFound(None), ApiError::Gone(None));
+    assert_ne!(ApiError::Other(418, None), ApiError::Other(451, None));
+    assert_ne!(
+        ApiError::NotFound(None),
+        ApiError::NotFound(None).with_request_id("req-1")
+    );
+}
+
+#[tokio::test]
+async fn test_clone_renders_same_body() {
+    use std::error::Error;
+
+    let error = ApiError::conflict("Email already registered")
+        .with_conflicting_field("email")
+        .with_request_id("req-9")
+        .with_source("duplicate key value violates unique constraint");
+    let clone = error.clone();
+
+    assert_eq!(clone, error);
+    assert!(clone.source().is_some());
+    let (_, _, original) = render(error).await;
+    let (_, _, cloned) = render(clone).await;
+    assert_eq!(cloned, original);
+}
+
+#[tokio::test]
+async fn test_rate_limited_headers_and_body() {
+    let response = ApiError::rate_limited(100, 0, Duration::from_millis(29_500)).into_response();
+
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.headers()["x-ratelimit-limit"], "100");
+    assert_eq!(response.headers()["x-ratelimit-remaining"], "0");
+    assert_eq!(response.headers()["x-ratelimit-reset"], "30");
+    assert_eq!(response.headers()[header::RETRY_AFTER], "30");
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(
+        parse_json(&body),
+        json!({
+            "error": "rate_limited", "status": 429,
+            "rate_limit": { "limit": 100, "remaining": 0, "reset": 30 }
+        })
+    );
+}
+
+#[test]
+fn test_with_retry_after_keeps_rate_limit() {
+    let error = ApiError::rate_limited(10, 0, Duration::from_secs(60))
+        .with_retry_after(Duration::from_secs(5));
+
+    assert!(matches!(
+        error,
+        ApiError::TooManyRequests {
+            retry_after: Some(retry_after),
+            rate_limit: Some(RateLimit { limit: 10, .. }),
+            ..
+        } if retry_after == Duration::from_secs(5)
+    ));
+}
+
+#[tokio::test]
+async fn test_status_in_body() {
+    let (_, _, not_found) = render(ApiError::NotFound(None)).await;
+    let (_, _, teapot) = render(ApiError::Other(418, Some("I'm a teapot".to_string()))).await;
+    let (_, _, wrapped) = render(ApiError::Gone(None).with_request_id("req-1")).await;
+
+    assert_eq!(not_found["status"], 404);
+    assert_eq!(teapot["status"], 418);
+    assert_eq!(wrapped["status"], 410);
+}
+
+#[test]
+fn test_default_message_is_not_allocated() {
+    let default = ApiError::NotFound(None).to_body();
+    let custom = ApiError::not_found("User not found").to_body();
+
+    assert_eq!(default.message, None);
+    assert_eq!(custom.message.as_deref(), Some("User not found"));
+}
+
+#[tokio::test]
+async fn test_content_length_matches_body() {
+    let responses = [
+        ApiError::not_found("User not found").into_response(),
+        ApiError::NotFound(None).into_problem_response(),
+        ApiError::Gone(None).into_localized_response(skyak_axum_core::locale::Lang::Es),
+    ];
+
+    for response in responses {
+        let length: usize = response.headers()[header::CONTENT_LENGTH]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(response.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .ends_with("json"));
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(length, body.len());
+    }
+}
+
+#[tokio::test]
+async fn test_multiple_uses_most_severe_status() {
+    let error = ApiError::multiple([
+        ApiError::bad_request("Quantity must be positive"),
+        ApiError::not_found("Product 42 does not exist"),
+    ]);
+    let (status, _, body) = render(error).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(
+        body,
+        json!({
+            "error": "multiple_errors",
+            "status": 404,
+            "details": [
+                { "error": "bad_request", "status": 400, "message": "Quantity must be positive" },
+                { "error": "not_found", "status": 404, "message": "Product 42 does not exist" },
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_multiple_server_error_wins() {
+    let error = ApiError::multiple([
+        ApiError::NotFound(None),
+        ApiError::ServiceUnavailable {
+            message: None,
+            retry_after: None,
+        },
+        ApiError::BadRequest(None),
+    ]);
+
+    assert_eq!(error.to_body().status, 503);
+}
+
+#[test]
+fn test_multiple_body_round_trips() {
+    let body = ApiError::multiple([ApiError::Gone(None), ApiError::NotFound(None)]).to_body();
+    let parsed: ApiErrorBody =
+        serde_json::from_str(&serde_json::to_string(&body).unwrap()).unwrap();
+
+    assert_eq!(parsed.details.len(), 2);
+    assert_eq!(parsed, body);
+}
+
+#[test]
+fn test_status_classification() {
+    let cases = [
+        (ApiError::NotFound(None), StatusCode::NOT_FOUND, true),
+        (ApiError::conflict("taken"), StatusCode::CONFLICT, true),
+        (
+            ApiError::InternalServerError(None),
+            StatusCode::INTERNAL_SERVER_ERROR,
+            false,
+        ),
+        (
+            ApiError::bad_gateway("down"),
+            StatusCode::BAD_GATEWAY,
+            false,
+        ),
+        (ApiError::Other(418, None), StatusCode::IM_A_TEAPOT, true),
+        (
+            ApiError::Other(507, None),
+            StatusCode::INSUFFICIENT_STORAGE,
+            false,
+        ),
+        (
+            ApiError::Forbidden(None).with_request_id("req-1"),
+            StatusCode::FORBIDDEN,
+            true,
+        ),
+    ];
+
+    for (error, status, client) in cases {
+        assert_eq!(error.status_code(), status);
+        assert_eq!(error.is_client_error(), client);
+        assert_eq!(error.is_server_error(), !client);
+    }
+}
+
+#[tokio::test]
+async fn test_default_message_matches_text_body() {
+    assert_eq!(ApiError::NotFound(None).default_message(), "Not Found");
+
+    for error in [
+        ApiError::NotFound(None),
+        ApiError::Gone(None),
+        ApiError::Other(418, None),
+    ] {
+        let expected = error.default_message();
+        let (_, _, body) = render(error.clone()).await;
+        assert!(body.get("message").is_none());
+
+        let response = error.into_response_for("text/plain");
+        let text = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(text, expected.as_bytes());
+    }
+}
+
+#[tokio::test]
+async fn test_message_omitted_without_custom_message() {
+    let (_, _, omitted) = render(ApiError::NotFound(None)).await;
+    let (_, _, present) = render(ApiError::not_found("User not found")).await;
+
+    assert_eq!(omitted, json!({ "error": "not_found", "status": 404 }));
+    assert_eq!(
+        present,
+        json!({ "error": "not_found", "status": 404, "message": "User not found" })
+    );
+}
+
+#[test]
+fn test_try_from_status_code() {
+    assert_eq!(
+        ApiError::try_from(StatusCode::NOT_FOUND),
+        Ok(ApiError::NotFound(None))
+    );
+    assert_eq!(
+        ApiError::try_from(StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS),
+        Ok(ApiError::Other(451, None))
+    );
+    assert_eq!(ApiError::try_from(StatusCode::OK), Err(InvalidStatus(200)));
+    assert_eq!(
+        ApiError::try_from(StatusCode::MOVED_PERMANENTLY),
+        Err(InvalidStatus(301))
+    );
+}
+
+#[tokio::test]
+async fn test_validation_renders_pointer_errors() {
+    let error = ApiError::validation(vec![
+        ("/items/0/price".to_string(), "must be positive".to_string()),
+        ("/customer/email".to_string(), "invalid".to_string()),
+    ]);
+
+    let (status, _, body) = render(error).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        body,
+        json!({
+            "error": "validation_failed",
+            "status": 422,
+            "errors": [
+                { "pointer": "/items/0/price", "message": "must be positive" },
+                { "pointer": "/customer/email", "message": "invalid" }
+            ]
+        })
+    );
+}
+
+#[cfg(not(feature = "dev-backtrace"))]
+#[tokio::test]
+async fn test_backtrace_never_rendered_without_feature() {
+    let error = ApiError::internal_server_error_with_backtrace("Cache corrupted");
+    assert_eq!(error, ApiError::internal_server_error("Cache corrupted"));
+
+    let (_, _, body) = render(error).await;
+    assert!(body.get("backtrace").is_none());
+}
+
+#[tokio::test]
+async fn test_other_convenience_constructors() {
+    let cases = [
+        (ApiError::payment_required("Upgrade your plan"), 402),
+        (ApiError::precondition_failed("ETag mismatch"), 412),
+        (ApiError::unsupported_media_type("Use JSON"), 415),
+        (ApiError::im_a_teapot("Short and stout"), 418),
+        (ApiError::locked("Account locked"), 423),
+        (
+            ApiError::unavailable_for_legal_reasons("Blocked in your region"),
+            451,
+        ),
+    ];
+
+    for (error, status) in cases {
+        assert!(matches!(error, ApiError::Other(code, Some(_)) if code == status));
+        let (rendered, _, body) = render(error).await;
+        assert_eq!(rendered.as_u16(), status);
+        assert_eq!(body["error"], format!("http_{status}"));
+    }
+}
+
+#[tokio::test]
+async fn test_into_http_response() {
+    use http_body_util::BodyExt;
+
+    let response = ApiError::conflict("Email taken")
+        .with_request_id("req-9")
+        .into_http_response();
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    assert_eq!(response.headers()["x-request-id"], "req-9");
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(
+        parse_json(&body),
+        json!({
+            "error": "conflict",
+            "status": 409,
+            "message": "Email taken",
+            "request_id": "req-9"
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_error_aggregator_dedups_identical_errors() {
+    let mut aggregator = ErrorAggregator::new();
+    assert!(aggregator.is_empty());
+    aggregator.push(ApiError::bad_request("Name is required"));
+    aggregator.push(ApiError::conflict("Email taken"));
+    aggregator.push(ApiError::bad_request("Name is required"));
+
+    let error = aggregator.build().unwrap();
+    assert_eq!(
+        error,
+        ApiError::multiple([
+            ApiError::bad_request("Name is required"),
+            ApiError::conflict("Email taken"),
+        ])
+    );
+    let (_, _, body) = render(error).await;
+    assert_eq!(body["details"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_error_aggregator_empty_builds_nothing() {
+    assert_eq!(ErrorAggregator::new().build(), None);
+}
+
+#[test]
+fn test_other_or_fallback_status() {
+    assert_eq!(
+        ApiError::other_or(451, StatusCode::BAD_GATEWAY, Some("Blocked".to_string())),
+        ApiError::Other(451, Some("Blocked".to_string()))
+    );
+
+    let error = ApiError::other_or(42, StatusCode::BAD_GATEWAY, Some("Bogus".to_string()));
+    assert_eq!(error, ApiError::bad_gateway("Bogus"));
+    assert_eq!(error.status_code(), StatusCode::BAD_GATEWAY);
+
+    let error = ApiError::other_or(42, StatusCode::OK, None);
+    assert_eq!(error.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        ApiError::Other(42, None).status_code(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
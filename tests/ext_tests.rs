@@ -0,0 +1,58 @@
+use skyak_axum_core::errors::{ApiError, OptionExt, ResultExt};
+
+#[test]
+fn test_result_not_found() {
+    let result: Result<u32, String> = Err("user 7 does not exist".to_string());
+
+    assert_eq!(
+        result.not_found(),
+        Err(ApiError::not_found("user 7 does not exist"))
+    );
+}
+
+#[test]
+fn test_result_bad_request_uses_display() {
+    let result = "abc".parse::<u32>().bad_request();
+
+    assert_eq!(
+        result,
+        Err(ApiError::bad_request("invalid digit found in string"))
+    );
+}
+
+#[test]
+fn test_result_internal_and_conflict() {
+    let failed: Result<(), &str> = Err("pool exhausted");
+    let duplicate: Result<(), &str> = Err("email taken");
+
+    assert_eq!(
+        failed.internal(),
+        Err(ApiError::internal_server_error("pool exhausted"))
+    );
+    assert_eq!(duplicate.conflict(), Err(ApiError::conflict("email taken")));
+}
+
+#[test]
+fn test_result_ok_passes_through() {
+    let result: Result<u32, String> = Ok(7);
+
+    assert_eq!(result.forbidden(), Ok(7));
+}
+
+#[test]
+fn test_option_some_passes_through() {
+    assert_eq!(Some(7).or_not_found(), Ok(7));
+    assert_eq!(
+        Some("Alice").or_not_found_msg("User not found"),
+        Ok("Alice")
+    );
+}
+
+#[test]
+fn test_option_none_is_not_found() {
+    assert_eq!(None::<u32>.or_not_found(), Err(ApiError::NotFound(None)));
+    assert_eq!(
+        None::<u32>.or_not_found_msg("User not found"),
+        Err(ApiError::not_found("User not found"))
+    );
+}
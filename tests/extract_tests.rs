@@ -0,0 +1,201 @@
+use axum::body::{to_bytes, Body};
+use axum::extract::{FromRequest, FromRequestParts, Request};
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use serde::Deserialize;
+use serde_json::Value;
+use skyak_axum_core::errors::ApiError;
+use skyak_axum_core::extract::{ApiJson, ApiPath, ApiQuery};
+use tower::ServiceExt;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct CreateUser {
+    name: String,
+    age: u32,
+}
+
+fn json_request(body: &'static str) -> Request {
+    Request::builder()
+        .method("POST")
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .unwrap()
+}
+
+async fn rejection_body(error: ApiError) -> (StatusCode, Value) {
+    let response = error.into_response();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_api_json_parses_body() {
+    let request = json_request(r#"{"name": "Ada", "age": 36}"#);
+    let ApiJson(user) = ApiJson::<CreateUser>::from_request(request, &())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        user,
+        CreateUser {
+            name: "Ada".to_string(),
+            age: 36
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_api_json_malformed_body_is_bad_request() {
+    let request = json_request(r#"{"name": "Ada""#);
+    let error = ApiJson::<CreateUser>::from_request(request, &())
+        .await
+        .unwrap_err();
+    let (status, body) = rejection_body(error).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], "bad_request");
+    assert!(body["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Failed to parse the request body as JSON"));
+}
+
+#[tokio::test]
+async fn test_api_json_wrong_shape_is_unprocessable() {
+    let request = json_request(r#"{"name": "Ada", "age": "old"}"#);
+    let error = ApiJson::<CreateUser>::from_request(request, &())
+        .await
+        .unwrap_err();
+    let (status, body) = rejection_body(error).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"], "unprocessable_entity");
+}
+
+#[tokio::test]
+async fn test_api_json_missing_content_type_is_bad_request() {
+    let request = Request::builder()
+        .method("POST")
+        .body(Body::from(r#"{"name": "Ada", "age": 36}"#))
+        .unwrap();
+    let error = ApiJson::<CreateUser>::from_request(request, &())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, ApiError::BadRequest(Some(_))));
+}
+
+#[derive(Debug, Deserialize)]
+struct Search {
+    q: String,
+    page: Option<u32>,
+}
+
+async fn extract_query(uri: &str) -> Result<ApiQuery<Search>, ApiError> {
+    let (mut parts, _) = Request::builder().uri(uri).body(()).unwrap().into_parts();
+    ApiQuery::<Search>::from_request_parts(&mut parts, &()).await
+}
+
+#[tokio::test]
+async fn test_api_query_parses_query_string() {
+    let query = extract_query("/search?q=rust&page=2").await.unwrap();
+
+    assert_eq!(query.q, "rust");
+    assert_eq!(query.page, Some(2));
+}
+
+#[tokio::test]
+async fn test_api_query_type_mismatch_is_bad_request() {
+    let error = extract_query("/search?q=rust&page=two").await.unwrap_err();
+    let (status, body) = rejection_body(error).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], "bad_request");
+    assert!(body["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Failed to deserialize query string"));
+}
+
+async fn get_user(ApiPath(id): ApiPath<u32>) -> String {
+    format!("user {id}")
+}
+
+async fn get_path(uri: &str) -> (StatusCode, axum::body::Bytes) {
+    let app = Router::new().route("/users/{id}", get(get_user));
+    let response = app
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    (
+        status,
+        to_bytes(response.into_body(), usize::MAX).await.unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn test_api_path_parses_param() {
+    let (status, body) = get_path("/users/42").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "user 42");
+}
+
+#[tokio::test]
+async fn test_api_path_non_numeric_is_bad_request() {
+    let (status, body) = get_path("/users/abc").await;
+    let body: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], "bad_request");
+    assert!(body["message"]
+        .as_str()
+        .unwrap()
+        .contains("Cannot parse `abc` to a `u32`"));
+}
+
+#[tokio::test]
+async fn test_from_json_rejection() {
+    let rejection = axum::Json::<CreateUser>::from_request(json_request("{"), &())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        ApiError::from(rejection),
+        ApiError::BadRequest(Some(_))
+    ));
+
+    let rejection =
+        axum::Json::<CreateUser>::from_request(json_request(r#"{"name": 1, "age": 2}"#), &())
+            .await
+            .unwrap_err();
+    assert!(matches!(
+        ApiError::from(rejection),
+        ApiError::UnprocessableEntity {
+            message: Some(_),
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_from_query_rejection() {
+    let (mut parts, _) = Request::builder()
+        .uri("/search?page=2")
+        .body(())
+        .unwrap()
+        .into_parts();
+    let rejection = axum::extract::Query::<Search>::from_request_parts(&mut parts, &())
+        .await
+        .unwrap_err();
+    let (status, body) = rejection_body(ApiError::from(rejection)).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["message"]
+        .as_str()
+        .unwrap()
+        .contains("missing field `q`"));
+}
@@ -0,0 +1,82 @@
+use axum::http::header;
+use axum::response::IntoResponse;
+use skyak_axum_core::errors::ApiError;
+use skyak_axum_core::headers::CacheControl;
+use skyak_axum_core::https;
+use std::time::Duration;
+
+#[test]
+fn test_max_age_public() {
+    let cache_control = CacheControl::new()
+        .public()
+        .max_age(Duration::from_secs(300));
+
+    assert_eq!(cache_control.header_value().unwrap(), "max-age=300, public");
+}
+
+#[test]
+fn test_private_no_cache_must_revalidate() {
+    let cache_control = CacheControl::new().private().no_cache().must_revalidate();
+
+    assert_eq!(
+        cache_control.header_value().unwrap(),
+        "private, no-cache, must-revalidate"
+    );
+}
+
+#[test]
+fn test_no_store() {
+    assert_eq!(
+        CacheControl::new().no_store().header_value().unwrap(),
+        "no-store"
+    );
+}
+
+#[test]
+fn test_no_store_with_max_age_is_rejected() {
+    let error = CacheControl::new()
+        .no_store()
+        .max_age(Duration::from_secs(60))
+        .header_value()
+        .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "invalid Cache-Control: no-store cannot be combined with max-age"
+    );
+}
+
+#[test]
+fn test_public_with_private_is_rejected() {
+    assert!(CacheControl::new()
+        .public()
+        .private()
+        .header_value()
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_success_cached() {
+    let cache_control = CacheControl::new()
+        .max_age(Duration::from_secs(60))
+        .public();
+    let response = https::success_cached("Hello".to_string(), cache_control).into_response();
+
+    assert_eq!(
+        response.headers()[header::CACHE_CONTROL],
+        "max-age=60, public"
+    );
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+}
+
+#[tokio::test]
+async fn test_success_cached_with_conflict() {
+    let cache_control = CacheControl::new()
+        .no_store()
+        .max_age(Duration::from_secs(60));
+
+    assert!(matches!(
+        https::success_cached("Hello".to_string(), cache_control),
+        Err(ApiError::InternalServerError(Some(_)))
+    ));
+}
@@ -1,6 +1,17 @@
+use axum::body::to_bytes;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use http_body_util::BodyExt;
+use serde_json::json;
+use serde_json::Value;
 use skyak_axum_core::errors;
 use skyak_axum_core::https;
 
+async fn body_json(response: Response) -> Value {
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
 #[tokio::test]
 async fn test_success() {
     let data = "Test data".to_string();
@@ -15,10 +26,634 @@ async fn test_error() {
     let error = errors::ApiError::NotFound(Some(error_message.to_string()));
     let response: https::ApiResponse<String> = https::error(error);
 
-    assert!(response.is_err());
+    assert_eq!(
+        response.unwrap_err(),
+        errors::ApiError::NotFound(Some(error_message.to_string()))
+    );
+}
+
+#[tokio::test]
+async fn test_success_enveloped() {
+    #[derive(serde::Serialize)]
+    struct User {
+        id: i32,
+        name: String,
+    }
+
+    let response = https::success_enveloped(User {
+        id: 1,
+        name: "Alice".to_string(),
+    });
+
+    let body = serde_json::to_value(&response.unwrap().0).unwrap();
+    assert_eq!(body, json!({ "data": { "id": 1, "name": "Alice" } }));
+}
+
+#[tokio::test]
+async fn test_created_with_location() {
+    let response = https::created(json!({ "id": 7 }), Some("/users/7"));
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(response.headers()[header::LOCATION], "/users/7");
+    assert_eq!(body_json(response).await, json!({ "id": 7 }));
+}
+
+#[tokio::test]
+async fn test_created_without_location() {
+    let response = https::created(json!({ "id": 7 }), None);
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert!(response.headers().get(header::LOCATION).is_none());
+}
+
+#[tokio::test]
+async fn test_created_with_invalid_location() {
+    let response = https::created(json!({ "id": 7 }), Some("/users/\n7"));
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(body_json(response).await["error"], "internal_server_error");
+}
+
+#[tokio::test]
+async fn test_no_content() {
+    let response = https::no_content();
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert!(response.headers().get(header::CONTENT_TYPE).is_none());
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn test_upsert_response_created() {
+    let response = https::upsert_response(json!({ "id": 1 }), true);
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(body_json(response).await, json!({ "id": 1 }));
+}
+
+#[tokio::test]
+async fn test_upsert_response_updated() {
+    let response = https::upsert_response(json!({ "id": 1 }), false);
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    assert_eq!(body_json(response).await, json!({ "id": 1 }));
+}
+
+#[tokio::test]
+async fn test_ok_has_empty_body() {
+    let response = https::ok();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get(header::CONTENT_TYPE).is_none());
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert!(body.is_empty());
+    assert_ne!(&body[..], b"null");
+}
+
+#[tokio::test]
+async fn test_no_content_with_headers() {
+    let mut headers = axum::http::HeaderMap::new();
+    headers.insert("x-deleted-id", axum::http::HeaderValue::from_static("42"));
+    let response = https::no_content_with_headers(headers);
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(response.headers()["x-deleted-id"], "42");
+    assert!(response.headers().get(header::CONTENT_TYPE).is_none());
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn test_accepted() {
+    let response = https::accepted(json!({ "job_id": "job-42" }));
+
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    assert_eq!(body_json(response).await, json!({ "job_id": "job-42" }));
+}
+
+#[tokio::test]
+async fn test_success_with_status_ok() {
+    let response = https::success_with_status(StatusCode::OK, json!({ "id": 1 }))
+        .unwrap()
+        .into_response();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body_json(response).await, json!({ "id": 1 }));
+}
+
+#[tokio::test]
+async fn test_success_with_status_partial_content() {
+    let response =
+        https::success_with_status(StatusCode::PARTIAL_CONTENT, vec![1, 2, 3]).into_response();
+
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    assert_eq!(body_json(response).await, json!([1, 2, 3]));
+}
+
+#[tokio::test]
+async fn test_paginated_exact_multiple() {
+    let page = https::success_paginated(vec![1, 2, 3], 9, 1, 3).unwrap().0;
+
+    assert_eq!(page.total_pages, 3);
+    assert_eq!(
+        serde_json::to_value(&page).unwrap(),
+        json!({ "items": [1, 2, 3], "total": 9, "page": 1, "per_page": 3, "total_pages": 3 })
+    );
+}
+
+#[tokio::test]
+async fn test_paginated_rounds_up_remainder() {
+    let page = https::success_paginated(vec![1, 2, 3], 10, 1, 3).unwrap().0;
+    assert_eq!(page.total_pages, 4);
+}
+
+#[tokio::test]
+async fn test_paginated_zero_per_page() {
+    let page = https::success_paginated(Vec::<i32>::new(), 10, 1, 0)
+        .unwrap()
+        .0;
+    assert_eq!(page.total_pages, 0);
+}
+
+#[tokio::test]
+async fn test_cursor_page_with_cursor() {
+    let page = https::success_cursor(vec!["a", "b"], Some("next".to_string()))
+        .unwrap()
+        .0;
+
+    assert!(page.has_more);
+    assert_eq!(
+        serde_json::to_value(&page).unwrap(),
+        json!({ "items": ["a", "b"], "next_cursor": "next", "has_more": true })
+    );
+}
+
+#[tokio::test]
+async fn test_cursor_page_final_page() {
+    let page = https::success_cursor(vec!["c"], None).unwrap().0;
+
+    assert!(!page.has_more);
+    assert_eq!(
+        serde_json::to_value(&page).unwrap(),
+        json!({ "items": ["c"], "next_cursor": null, "has_more": false })
+    );
+}
+
+#[tokio::test]
+async fn test_success_with_headers() {
+    let response = https::success_with_headers(json!({ "id": 1 }), [("x-request-id", "abc-123")])
+        .into_response();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-request-id"], "abc-123");
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    assert_eq!(body_json(response).await, json!({ "id": 1 }));
+}
+
+#[tokio::test]
+async fn test_success_with_invalid_header_value() {
+    let result = https::success_with_headers(json!({}), [("x-request-id", "bad\nvalue")]);
+
+    match result {
+        Err(errors::ApiError::InternalServerError(Some(message))) => {
+            assert_eq!(message, "Invalid header value")
+        }
+        _ => panic!("Expected InternalServerError error variant"),
+    }
+}
+
+#[tokio::test]
+async fn test_success_with_etag_cache_miss() {
+    let response = https::success_with_etag(json!({ "id": 1 }), Some("\"stale\""));
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let etag = response.headers()[header::ETAG]
+        .to_str()
+        .unwrap()
+        .to_string();
+    assert!(etag.starts_with('"') && etag.ends_with('"'));
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    assert_eq!(body_json(response).await, json!({ "id": 1 }));
+
+    let again = https::success_with_etag(json!({ "id": 1 }), None);
+    assert_eq!(again.headers()[header::ETAG], etag.as_str());
+}
+
+#[tokio::test]
+async fn test_success_with_etag_cache_hit() {
+    let first = https::success_with_etag(json!({ "id": 1 }), None);
+    let etag = first.headers()[header::ETAG].to_str().unwrap().to_string();
+
+    let response = https::success_with_etag(json!({ "id": 1 }), Some(&etag));
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[header::ETAG], etag.as_str());
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert!(body.is_empty());
+
+    let weak_list = format!("\"other\", W/{etag}");
+    let response = https::success_with_etag(json!({ "id": 1 }), Some(&weak_list));
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+    let response = https::success_with_etag(json!({ "id": 1 }), Some("*"));
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+}
+
+#[tokio::test]
+async fn test_redirects() {
+    let cases = vec![
+        (
+            https::redirect_permanent("/v2/users"),
+            StatusCode::MOVED_PERMANENTLY,
+        ),
+        (
+            https::redirect_temporary("/v2/users"),
+            StatusCode::TEMPORARY_REDIRECT,
+        ),
+        (https::see_other("/v2/users"), StatusCode::SEE_OTHER),
+    ];
+
+    for (response, expected_status) in cases {
+        assert_eq!(response.status(), expected_status);
+        assert_eq!(response.headers()[header::LOCATION], "/v2/users");
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(body.is_empty());
+    }
+}
+
+#[tokio::test]
+async fn test_redirect_with_invalid_location() {
+    let response = https::see_other("/orders\r\n42");
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(response.headers().get(header::LOCATION).is_none());
+}
+
+#[tokio::test]
+async fn test_success_list() {
+    let list = https::success_list(vec!["a", "b", "c"]).unwrap().0;
+
+    assert_eq!(list.count, 3);
+    assert_eq!(
+        serde_json::to_value(&list).unwrap(),
+        json!({ "items": ["a", "b", "c"], "count": 3 })
+    );
+}
+
+#[tokio::test]
+async fn test_success_with_closure_sets_header() {
+    let response = https::success_with(json!({ "id": 1 }), |response| {
+        response
+            .headers_mut()
+            .insert("x-api-version", axum::http::HeaderValue::from_static("2"));
+    });
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-api-version"], "2");
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    assert_eq!(body_json(response).await, json!({ "id": 1 }));
+}
+
+#[tokio::test]
+async fn test_not_found_fallback() {
+    use axum::routing::get;
+    use tower::ServiceExt;
+
+    let app = axum::Router::new()
+        .route("/health", get(|| async { "ok" }))
+        .fallback(https::not_found_handler);
+    let request = axum::http::Request::builder()
+        .uri("/missing")
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body = body_json(response).await;
+    assert_eq!(body["error"], "not_found");
+    assert!(body.get("message").is_none());
+}
+
+#[tokio::test]
+async fn test_success_stream_ndjson() {
+    let items = (1..=3).map(|id| json!({ "id": id, "name": format!("user-{id}") }));
+    let response = https::success_stream(futures_util::stream::iter(items));
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/x-ndjson"
+    );
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.ends_with('\n'));
+    let lines: Vec<Value> = body
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            json!({ "id": 1, "name": "user-1" }),
+            json!({ "id": 2, "name": "user-2" }),
+            json!({ "id": 3, "name": "user-3" }),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_success_stream_serialization_error_aborts_body() {
+    struct Unserializable;
+
+    impl serde::Serialize for Unserializable {
+        fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("cannot serialize"))
+        }
+    }
+
+    let response = https::success_stream(futures_util::stream::iter([Unserializable]));
+
+    assert!(to_bytes(response.into_body(), usize::MAX).await.is_err());
+}
+
+#[tokio::test]
+async fn test_success_stream_with_trailers_reports_error() {
+    let items = vec![
+        Ok(json!({ "id": 1 })),
+        Err(errors::ApiError::service_unavailable("Replica lost")),
+        Ok(json!({ "id": 2 })),
+    ];
+    let response = https::success_stream_with_trailers(futures_util::stream::iter(items));
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::TRAILER],
+        "x-error-status, x-error-code, x-error-message"
+    );
+
+    let collected = response.into_body().collect().await.unwrap();
+    let trailers = collected.trailers().cloned().expect("missing trailers");
+    assert_eq!(collected.to_bytes(), "{\"id\":1}\n");
+    assert_eq!(trailers["x-error-status"], "503");
+    assert_eq!(trailers["x-error-code"], "service_unavailable");
+    assert_eq!(trailers["x-error-message"], "Replica lost");
+}
+
+#[tokio::test]
+async fn test_success_stream_with_trailers_clean_stream() {
+    let items = vec![Ok::<_, errors::ApiError>(1), Ok(2)];
+    let response = https::success_stream_with_trailers(futures_util::stream::iter(items));
+
+    let collected = response.into_body().collect().await.unwrap();
+    assert!(collected.trailers().is_none());
+    assert_eq!(collected.to_bytes(), "1\n2\n");
+}
+
+#[tokio::test]
+async fn test_success_try_stream_clean() {
+    let items = vec![
+        Ok::<_, errors::ApiError>(json!({ "id": 1 })),
+        Ok(json!({ "id": 2 })),
+    ];
+    let response = https::success_try_stream(futures_util::stream::iter(items)).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/x-ndjson"
+    );
+    let collected = response.into_body().collect().await.unwrap();
+    assert!(collected.trailers().is_none());
+    assert_eq!(collected.to_bytes(), "{\"id\":1}\n{\"id\":2}\n");
+}
+
+#[tokio::test]
+async fn test_success_try_stream_error_before_first_item() {
+    let items = vec![Err(errors::ApiError::not_found("No such export")), Ok(1)];
+    let response = https::success_try_stream(futures_util::stream::iter(items)).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    let body = body_json(response).await;
+    assert_eq!(body["error"], "not_found");
+    assert_eq!(body["message"], "No such export");
+}
+
+#[tokio::test]
+async fn test_success_try_stream_error_after_first_item() {
+    let items = vec![Ok(1), Err(errors::ApiError::bad_gateway("Upstream closed"))];
+    let response = https::success_try_stream(futures_util::stream::iter(items)).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let collected = response.into_body().collect().await.unwrap();
+    let trailers = collected.trailers().cloned().expect("missing trailers");
+    assert_eq!(collected.to_bytes(), "1\n");
+    assert_eq!(trailers["x-error-status"], "502");
+    assert_eq!(trailers["x-error-code"], "bad_gateway");
+}
+
+#[tokio::test]
+async fn test_success_bytes() {
+    let pdf = b"%PDF-1.7 fake".to_vec();
+    let response = https::success_bytes(pdf.clone(), "application/pdf");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/pdf");
+    assert_eq!(
+        response.headers()[header::CONTENT_LENGTH],
+        pdf.len().to_string()
+    );
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(body, pdf);
+}
+
+#[tokio::test]
+async fn test_success_bytes_with_invalid_content_type() {
+    let response = https::success_bytes(vec![1, 2, 3], "image/png\n");
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(body_json(response).await["error"], "internal_server_error");
+}
+
+#[tokio::test]
+async fn test_download_ascii_filename() {
+    let response = https::download(b"id,name\n".to_vec(), "users \"2024\".csv", "text/csv");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "text/csv");
+    assert_eq!(response.headers()[header::CONTENT_LENGTH], "8");
+    assert_eq!(
+        response.headers()[header::CONTENT_DISPOSITION],
+        r#"attachment; filename="users \"2024\".csv""#
+    );
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(body, "id,name\n");
+}
+
+#[tokio::test]
+async fn test_download_utf8_filename() {
+    let response = https::download(vec![0; 4], "résumé 📄.pdf", "application/pdf");
+
+    assert_eq!(
+        response.headers()[header::CONTENT_DISPOSITION],
+        "attachment; filename=\"r_sum_ _.pdf\"; filename*=UTF-8''r%C3%A9sum%C3%A9%20%F0%9F%93%84.pdf"
+    );
+}
+
+#[tokio::test]
+async fn test_api_result_with_custom_error() {
+    struct AccountLocked;
+
+    impl IntoResponse for AccountLocked {
+        fn into_response(self) -> Response {
+            (StatusCode::LOCKED, "account locked").into_response()
+        }
+    }
 
-    match response.unwrap_err() {
-        errors::ApiError::NotFound(Some(msg)) => assert_eq!(msg, error_message),
-        _ => panic!("Expected NotFound error variant"),
+    fn handler(locked: bool) -> https::ApiResult<Value, AccountLocked> {
+        if locked {
+            Err(AccountLocked)
+        } else {
+            Ok(axum::Json(json!({ "ok": true })))
+        }
     }
+
+    let response = handler(true).into_response();
+    assert_eq!(response.status(), StatusCode::LOCKED);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(body, "account locked");
+
+    let response = handler(false).into_response();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body_json(response).await, json!({ "ok": true }));
+
+    let defaulted: https::ApiResult<String> = Err(errors::ApiError::NotFound(None));
+    let _: https::ApiResponse<String> = defaulted;
+}
+
+#[tokio::test]
+async fn test_success_vary() {
+    let response = https::success_vary(
+        json!({ "greeting": "Hola" }),
+        &["Accept", "Accept-Language"],
+    )
+    .unwrap()
+    .into_response();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::VARY], "Accept, Accept-Language");
+    assert_eq!(body_json(response).await, json!({ "greeting": "Hola" }));
+}
+
+#[test]
+fn test_success_vary_rejects_invalid_names() {
+    let result = https::success_vary("Hello", &["Accept Language"]);
+
+    assert!(matches!(
+        result,
+        Err(errors::ApiError::InternalServerError(Some(_)))
+    ));
+}
+
+#[test]
+fn test_respond_ok() {
+    let result: Result<u32, errors::ApiError> = Ok(7);
+
+    assert_eq!(https::respond(result).unwrap().0, 7);
+}
+
+#[test]
+fn test_respond_err() {
+    let result: Result<u32, errors::ApiError> = Err(errors::ApiError::conflict("Taken"));
+
+    assert_eq!(
+        https::respond(result).unwrap_err(),
+        errors::ApiError::conflict("Taken")
+    );
+}
+
+#[test]
+fn test_respond_found_some() {
+    let result: Result<Option<u32>, errors::ApiError> = Ok(Some(7));
+
+    assert_eq!(https::respond_found(result).unwrap().0, 7);
+}
+
+#[test]
+fn test_respond_found_none_is_not_found() {
+    let result: Result<Option<u32>, errors::ApiError> = Ok(None);
+
+    assert_eq!(
+        https::respond_found(result).unwrap_err(),
+        errors::ApiError::NotFound(None)
+    );
+}
+
+#[test]
+fn test_respond_found_error_is_converted() {
+    let result: Result<Option<u32>, std::io::Error> =
+        Err(std::io::Error::other("connection reset"));
+
+    assert_eq!(
+        https::respond_found(result).unwrap_err(),
+        errors::ApiError::internal_server_error("connection reset")
+    );
+}
+
+#[tokio::test]
+async fn test_success_media_type() {
+    let response = https::success_media_type(json!({ "id": 1 }), "application/vnd.myapp.v2+json");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/vnd.myapp.v2+json"
+    );
+    assert_eq!(body_json(response).await, json!({ "id": 1 }));
+}
+
+#[tokio::test]
+async fn test_success_media_type_invalid() {
+    let response =
+        https::success_media_type(json!({ "id": 1 }), "application/json\r\nX-Injected: 1");
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(response.headers().get("x-injected").is_none());
+}
+
+#[tokio::test]
+async fn test_success_with_envelope() {
+    let data = json!({ "id": 1 });
+
+    let bare = https::success_with_envelope::<https::Bare, _>(data.clone()).into_response();
+    let keyed = https::success_with_envelope::<https::DataKey, _>(data.clone()).into_response();
+    let result = https::success_with_envelope::<https::ResultKey, _>(data).into_response();
+
+    assert_eq!(body_json(bare).await, json!({ "id": 1 }));
+    assert_eq!(body_json(keyed).await, json!({ "data": { "id": 1 } }));
+    assert_eq!(body_json(result).await, json!({ "result": { "id": 1 } }));
+}
+
+#[tokio::test]
+async fn test_success_checked() {
+    let response = https::success_checked(json!({ "id": 1 }));
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    assert_eq!(body_json(response).await, json!({ "id": 1 }));
+}
+
+#[tokio::test]
+async fn test_success_checked_serialization_failure() {
+    let data = std::collections::HashMap::from([((1, 2), "tuple keys are not strings")]);
+    let response = https::success_checked(data);
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    let body = body_json(response).await;
+    assert_eq!(body["error"], "internal_server_error");
+    assert_eq!(body["message"], "serialization failed");
 }
@@ -0,0 +1,71 @@
+use axum::body::to_bytes;
+use axum::http::header;
+use axum::response::IntoResponse;
+use serde_json::Value;
+use skyak_axum_core::errors::ApiError;
+use skyak_axum_core::locale::Lang;
+
+#[test]
+fn test_not_found_defaults() {
+    let error = ApiError::NotFound(None);
+
+    assert_eq!(error.default_message_for(Lang::En), "Not Found");
+    assert_eq!(error.default_message_for(Lang::Es), "No encontrado");
+}
+
+#[test]
+fn test_custom_message_wins() {
+    let error = ApiError::not_found("Usuario no encontrado").with_request_id("req-1");
+
+    assert_eq!(error.message_for(Lang::En), "Usuario no encontrado");
+    assert_eq!(error.message_for(Lang::Es), "Usuario no encontrado");
+    assert_eq!(error.default_message_for(Lang::Es), "No encontrado");
+}
+
+#[test]
+fn test_from_accept_language() {
+    assert_eq!(Lang::from_accept_language("es"), Some(Lang::Es));
+    assert_eq!(
+        Lang::from_accept_language("fr-FR, en;q=0.5, es;q=0.7"),
+        Some(Lang::Es)
+    );
+    assert_eq!(
+        Lang::from_accept_language("en-GB, es;q=0.9"),
+        Some(Lang::En)
+    );
+    assert_eq!(Lang::from_accept_language("es;q=0, de"), None);
+    assert_eq!(Lang::from_accept_language(""), None);
+}
+
+#[tokio::test]
+async fn test_localized_response() {
+    let response = ApiError::NotFound(None).into_localized_response(Lang::Es);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(body["error"], "not_found");
+    assert_eq!(body["message"], "No encontrado");
+
+    let response = ApiError::NotFound(None).into_localized_response(Lang::En);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["message"], "Not Found");
+}
+
+#[test]
+fn test_localized_response_sets_content_language() {
+    let localized = ApiError::NotFound(None).into_localized_response(Lang::Es);
+    assert_eq!(localized.headers()[header::CONTENT_LANGUAGE], "es");
+
+    let english = ApiError::NotFound(None).into_localized_response(Lang::En);
+    assert!(english.headers().get(header::CONTENT_LANGUAGE).is_none());
+
+    let custom = ApiError::not_found("Usuario no encontrado").into_localized_response(Lang::Es);
+    assert!(custom.headers().get(header::CONTENT_LANGUAGE).is_none());
+
+    let unlocalized = ApiError::NotFound(None).into_response();
+    assert!(unlocalized
+        .headers()
+        .get(header::CONTENT_LANGUAGE)
+        .is_none());
+}
@@ -0,0 +1,62 @@
+use skyak_axum_core::errors::ApiError;
+use skyak_axum_core::https::{success, ApiResponse};
+use skyak_axum_core::{api_bail, api_ensure};
+
+fn find_user(id: u32) -> ApiResponse<String> {
+    match id {
+        0 => api_bail!(BadRequest),
+        1 => api_bail!(NotFound, "User {} not found", id),
+        2 => api_bail!(Conflict, "User {id} already exists"),
+        3 => api_bail!(TooManyRequests, "Slow down"),
+        4 => api_bail!(Other(451), "Unavailable in {}", "your region"),
+        _ => success(format!("user {id}")),
+    }
+}
+
+fn rename(name: &str) -> ApiResponse<String> {
+    api_ensure!(!name.is_empty(), BadRequest, "Name must not be empty");
+    api_ensure!(
+        name.len() <= 8,
+        UnprocessableEntity,
+        "Name is {} characters",
+        name.len()
+    );
+    api_ensure!(name != "root", Forbidden);
+    success(name.to_string())
+}
+
+#[test]
+fn test_api_bail() {
+    assert_eq!(find_user(0).unwrap_err(), ApiError::BadRequest(None));
+    assert_eq!(
+        find_user(1).unwrap_err(),
+        ApiError::not_found("User 1 not found")
+    );
+    assert_eq!(
+        find_user(2).unwrap_err(),
+        ApiError::conflict("User 2 already exists")
+    );
+    assert_eq!(
+        find_user(3).unwrap_err(),
+        ApiError::too_many_requests("Slow down")
+    );
+    assert_eq!(
+        find_user(4).unwrap_err(),
+        ApiError::Other(451, Some("Unavailable in your region".to_string()))
+    );
+    assert_eq!(find_user(5).unwrap().0, "user 5");
+}
+
+#[test]
+fn test_api_ensure() {
+    assert_eq!(
+        rename("").unwrap_err(),
+        ApiError::bad_request("Name must not be empty")
+    );
+    assert_eq!(
+        rename("a-very-long-name").unwrap_err(),
+        ApiError::unprocessable_entity("Name is 16 characters")
+    );
+    assert_eq!(rename("root").unwrap_err(), ApiError::Forbidden(None));
+    assert_eq!(rename("alice").unwrap().0, "alice");
+}
@@ -0,0 +1,338 @@
+use axum::body::{to_bytes, Body, Bytes};
+use axum::http::{header, Request, StatusCode};
+use axum::middleware::{from_fn, Next};
+use axum::response::Response;
+use axum::routing::{get, post};
+use axum::Router;
+use serde_json::Value;
+use skyak_axum_core::errors::ApiError;
+use skyak_axum_core::middleware::{
+    limit_body, require_json_accept, ApiTimeout, CatchPanic, DefaultMessages, ResponseTime,
+};
+use std::time::Duration;
+use tower::ServiceExt;
+
+async fn missing() -> ApiError {
+    ApiError::NotFound(None)
+}
+
+async fn missing_user() -> ApiError {
+    ApiError::not_found("User not found")
+}
+
+async fn forbidden() -> ApiError {
+    ApiError::Forbidden(None)
+}
+
+fn app() -> Router {
+    let public = Router::new()
+        .route("/missing", get(missing))
+        .route("/user", get(missing_user))
+        .route("/forbidden", get(forbidden))
+        .layer(DefaultMessages::new().message("not_found", "Nothing here"));
+    let internal = Router::new()
+        .route("/missing", get(missing))
+        .layer(DefaultMessages::new().message("not_found", "No route for this path"));
+
+    Router::new()
+        .nest("/public", public)
+        .nest("/internal", internal)
+        .route("/missing", get(missing))
+}
+
+async fn get_json(uri: &str) -> (StatusCode, Value) {
+    let response = app()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let length = response
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .map(|value| value.to_str().unwrap().parse::<usize>().unwrap());
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    if let Some(length) = length {
+        assert_eq!(length, body.len());
+    }
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_each_router_uses_its_own_defaults() {
+    let (status, public) = get_json("/public/missing").await;
+    let (_, internal) = get_json("/internal/missing").await;
+    let (_, unlayered) = get_json("/missing").await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(public["error"], "not_found");
+    assert_eq!(public["message"], "Nothing here");
+    assert_eq!(internal["message"], "No route for this path");
+    assert!(unlayered.get("message").is_none());
+}
+
+#[tokio::test]
+async fn test_nested_layer_keeps_inner_override() {
+    let public = Router::new()
+        .route("/missing", get(missing))
+        .layer(DefaultMessages::new().message("not_found", "Public override"));
+    let app = Router::new()
+        .nest("/public", public)
+        .route("/missing", get(missing))
+        .layer(DefaultMessages::new().message("not_found", "Global default"));
+
+    let mut messages = Vec::new();
+    for uri in ["/public/missing", "/missing"] {
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        messages.push(body["message"].clone());
+    }
+
+    assert_eq!(messages, ["Public override", "Global default"]);
+}
+
+#[tokio::test]
+async fn test_custom_messages_and_other_codes_untouched() {
+    let (_, user) = get_json("/public/user").await;
+    let (status, forbidden) = get_json("/public/forbidden").await;
+
+    assert_eq!(user["message"], "User not found");
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(forbidden.get("message").is_none());
+}
+
+/// Serves `/missing` with its body replaced by `body` below a `DefaultMessages` layer.
+async fn get_rewritten(body: fn() -> Body) -> Response {
+    Router::new()
+        .route("/missing", get(missing))
+        .layer(from_fn(
+            move |request: Request<Body>, next: Next| async move {
+                let (parts, _) = next.run(request).await.into_parts();
+                Response::from_parts(parts, body())
+            },
+        ))
+        .layer(DefaultMessages::new().message("not_found", "Nothing here"))
+        .oneshot(
+            Request::builder()
+                .uri("/missing")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_default_messages_pass_non_object_bodies_through() {
+    let response = get_rewritten(|| Body::from("[1,2]")).await;
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(body, "[1,2]");
+}
+
+#[tokio::test]
+async fn test_default_messages_fix_length_of_unreadable_bodies() {
+    let response = get_rewritten(|| {
+        Body::from_stream(futures_util::stream::iter([Err::<Bytes, _>(
+            std::io::Error::other("connection reset"),
+        )]))
+    })
+    .await;
+
+    // The stale length of the original body is gone; axum derives `0` for the empty body.
+    let length = response.headers().get(header::CONTENT_LENGTH).cloned();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert!(body.is_empty());
+    assert!(length.is_none_or(|length| length == "0"));
+}
+
+async fn panics() -> &'static str {
+    panic!("database handle poisoned")
+}
+
+async fn get_panicking(layer: CatchPanic) -> (StatusCode, String, Value) {
+    let app = Router::new()
+        .route("/panic", get(panics))
+        .route("/ok", get(|| async { "ok" }))
+        .layer(layer);
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/panic")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let content_type = response.headers()[header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, content_type, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_catch_panic_renders_standard_body() {
+    let (status, content_type, body) = get_panicking(CatchPanic::new()).await;
+
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(content_type, "application/json");
+    assert_eq!(body["error"], "internal_server_error");
+    assert!(body.get("message").is_none());
+}
+
+#[tokio::test]
+async fn test_catch_panic_can_expose_message() {
+    let (_, _, body) = get_panicking(CatchPanic::new().expose_message(true)).await;
+
+    assert_eq!(body["message"], "database handle poisoned");
+}
+
+async fn slow() -> &'static str {
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    "done"
+}
+
+fn timeout_app() -> Router {
+    Router::new()
+        .route("/slow", get(slow))
+        .route("/fast", get(|| async { "fast" }))
+        .layer(ApiTimeout::new(Duration::from_millis(20)))
+}
+
+#[tokio::test]
+async fn test_timeout_renders_gateway_timeout() {
+    let response = timeout_app()
+        .oneshot(Request::builder().uri("/slow").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["error"], "gateway_timeout");
+    assert!(body.get("message").is_none());
+}
+
+#[tokio::test]
+async fn test_timeout_passes_fast_responses() {
+    let response = timeout_app()
+        .oneshot(Request::builder().uri("/fast").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(body, "fast");
+}
+
+async fn get_with_accept(accept: &str) -> Response {
+    Router::new()
+        .route("/", get(|| async { "ok" }))
+        .layer(require_json_accept())
+        .oneshot(
+            Request::builder()
+                .uri("/")
+                .header(header::ACCEPT, accept)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_require_json_accept_passes_json_clients() {
+    for accept in [
+        "application/json",
+        "*/*",
+        "application/*",
+        "text/html, */*;q=0.1",
+    ] {
+        let response = get_with_accept(accept).await;
+        assert_eq!(response.status(), StatusCode::OK, "{accept}");
+    }
+}
+
+#[tokio::test]
+async fn test_require_json_accept_rejects_other_clients() {
+    let response = get_with_accept("text/html").await;
+
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["error"], "not_acceptable");
+}
+
+async fn post_limited(body: Body, content_length: Option<usize>) -> Response {
+    let mut request = Request::builder().method("POST").uri("/");
+    if let Some(length) = content_length {
+        request = request.header(header::CONTENT_LENGTH, length);
+    }
+    Router::new()
+        .route("/", post(|body: String| async move { body }))
+        .layer(limit_body(16))
+        .oneshot(request.body(body).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn assert_payload_too_large(response: Response) {
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["error"], "payload_too_large");
+    assert!(body.get("message").is_none());
+}
+
+#[tokio::test]
+async fn test_limit_body_rejects_declared_length() {
+    let payload = "x".repeat(17);
+    let response = post_limited(Body::from(payload), Some(17)).await;
+
+    assert_payload_too_large(response).await;
+}
+
+#[tokio::test]
+async fn test_limit_body_rejects_chunked_body() {
+    let chunks = ["x".repeat(10), "x".repeat(10)].map(Ok::<_, std::io::Error>);
+    let response = post_limited(Body::from_stream(futures_util::stream::iter(chunks)), None).await;
+
+    assert_payload_too_large(response).await;
+}
+
+#[tokio::test]
+async fn test_limit_body_passes_small_body() {
+    let response = post_limited(Body::from("hello"), Some(5)).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(body, "hello");
+}
+
+fn timed_app() -> Router {
+    Router::new()
+        .route("/", get(|| async { "ok" }))
+        .route("/missing", get(missing))
+        .layer(ResponseTime::new())
+}
+
+#[tokio::test]
+async fn test_response_time_on_success_and_error() {
+    for (uri, status) in [("/", StatusCode::OK), ("/missing", StatusCode::NOT_FOUND)] {
+        let response = timed_app()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), status);
+        let elapsed = response.headers()["x-response-time-ms"].to_str().unwrap();
+        assert!(elapsed.parse::<u64>().is_ok(), "{elapsed}");
+    }
+}
@@ -0,0 +1,95 @@
+//! The 5xx message flag is process-wide, so these tests live in their own binary and
+//! serialize on `FLAG` to avoid observing each other's setting. Responses are rendered
+//! while the lock is held; only reading the bodies happens afterwards.
+
+use axum::body::to_bytes;
+use axum::response::{IntoResponse, Response};
+use serde_json::Value;
+use skyak_axum_core::errors::{self, ApiError};
+use skyak_axum_core::locale::Lang;
+use std::sync::Mutex;
+
+static FLAG: Mutex<()> = Mutex::new(());
+
+/// Renders the responses with the flag set to `hide`, restoring the default afterwards.
+fn render_with(hide: bool, render: impl FnOnce() -> Vec<Response>) -> Vec<Response> {
+    let _guard = FLAG.lock().unwrap();
+    errors::hide_server_error_messages(hide);
+    let responses = render();
+    errors::hide_server_error_messages(false);
+    responses
+}
+
+async fn body(response: Response) -> Value {
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_server_error_message_hidden_when_enabled() {
+    let render =
+        || vec![ApiError::internal_server_error("connection refused: db:5432").into_response()];
+
+    let shown = body(render_with(false, render).remove(0)).await;
+    let hidden = body(render_with(true, render).remove(0)).await;
+
+    assert_eq!(shown["message"], "connection refused: db:5432");
+    assert_eq!(hidden["error"], "internal_server_error");
+    assert!(hidden.get("message").is_none());
+}
+
+#[test]
+fn test_display_keeps_detail_when_hidden() {
+    let _guard = FLAG.lock().unwrap();
+    errors::hide_server_error_messages(true);
+    let display = ApiError::internal_server_error("connection refused: db:5432").to_string();
+    errors::hide_server_error_messages(false);
+
+    assert_eq!(display, "InternalServerError: connection refused: db:5432");
+}
+
+#[tokio::test]
+async fn test_hidden_applies_to_all_server_errors_and_renderings() {
+    let mut responses = render_with(true, || {
+        vec![
+            ApiError::bad_gateway("upstream 10.0.0.3 down").into_response(),
+            ApiError::Other(507, Some("disk full".to_string())).into_response(),
+            ApiError::service_unavailable("pool exhausted").into_problem_response(),
+            ApiError::internal_server_error("panic at x.rs").into_localized_response(Lang::Es),
+            ApiError::internal_server_error("secret")
+                .with_request_id("req-1")
+                .into_response(),
+        ]
+    })
+    .into_iter();
+
+    let gateway = body(responses.next().unwrap()).await;
+    let other = body(responses.next().unwrap()).await;
+    let problem = body(responses.next().unwrap()).await;
+    let localized = body(responses.next().unwrap()).await;
+    let with_context = body(responses.next().unwrap()).await;
+
+    assert!(gateway.get("message").is_none());
+    assert!(other.get("message").is_none());
+    assert!(problem.get("detail").is_none());
+    assert_eq!(localized["message"], "Error interno del servidor");
+    assert!(with_context.get("message").is_none());
+    assert_eq!(with_context["request_id"], "req-1");
+}
+
+#[tokio::test]
+async fn test_client_errors_not_affected() {
+    let mut responses = render_with(true, || {
+        vec![
+            ApiError::not_found("User not found").into_response(),
+            ApiError::Other(418, Some("I'm a teapot".to_string())).into_response(),
+        ]
+    })
+    .into_iter();
+
+    let not_found = body(responses.next().unwrap()).await;
+    let teapot = body(responses.next().unwrap()).await;
+
+    assert_eq!(not_found["message"], "User not found");
+    assert_eq!(teapot["message"], "I'm a teapot");
+}
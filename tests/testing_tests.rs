@@ -0,0 +1,75 @@
+#![cfg(feature = "testing")]
+
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::{Deserialize, Serialize};
+use skyak_axum_core::errors::ApiError;
+use skyak_axum_core::https;
+use skyak_axum_core::testing::{assert_error, assert_success_json};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct User {
+    id: u32,
+    name: String,
+}
+
+#[tokio::test]
+async fn test_assert_error_returns_body() {
+    let response = ApiError::not_found("User not found")
+        .with_request_id("req-1")
+        .into_response();
+
+    let body = assert_error(response, StatusCode::NOT_FOUND, "User not found").await;
+
+    assert_eq!(body.error, "not_found");
+    assert_eq!(body.request_id.as_deref(), Some("req-1"));
+}
+
+#[tokio::test]
+async fn test_assert_error_without_message_uses_default() {
+    let response = ApiError::Gone(None).into_response();
+
+    let body = assert_error(response, StatusCode::GONE, "Gone").await;
+
+    assert_eq!(body.message, None);
+}
+
+#[tokio::test]
+#[should_panic(expected = "unexpected status")]
+async fn test_assert_error_wrong_status_panics() {
+    let response = ApiError::not_found("User not found").into_response();
+    assert_error(response, StatusCode::BAD_REQUEST, "User not found").await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "unexpected error message")]
+async fn test_assert_error_wrong_message_panics() {
+    let response = ApiError::not_found("User not found").into_response();
+    assert_error(response, StatusCode::NOT_FOUND, "Order not found").await;
+}
+
+#[tokio::test]
+async fn test_assert_success_json_returns_payload() {
+    let user = User {
+        id: 1,
+        name: "Alice".to_string(),
+    };
+    let response = https::success(user).into_response();
+
+    let user: User = assert_success_json(response).await;
+
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "Alice".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+#[should_panic(expected = "expected a successful response")]
+async fn test_assert_success_json_on_error_panics() {
+    let response = ApiError::not_found("User not found").into_response();
+    let _: User = assert_success_json(response).await;
+}
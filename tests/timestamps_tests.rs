@@ -0,0 +1,63 @@
+#![cfg(feature = "timestamps")]
+
+use axum::body::to_bytes;
+use axum::response::IntoResponse;
+use serde_json::Value;
+use skyak_axum_core::errors::ApiError;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Parses a `YYYY-MM-DDTHH:MM:SSZ` timestamp into Unix seconds.
+fn parse_rfc3339(timestamp: &str) -> Option<i64> {
+    let bytes = timestamp.as_bytes();
+    if bytes.len() != 20
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || bytes[10] != b'T'
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+        || bytes[19] != b'Z'
+    {
+        return None;
+    }
+    let field = |range: std::ops::Range<usize>| timestamp[range].parse::<i64>().ok();
+    let (year, month, day) = (field(0..4)?, field(5..7)?, field(8..10)?);
+    let (hour, minute, second) = (field(11..13)?, field(14..16)?, field(17..19)?);
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    // Howard Hinnant's days_from_civil.
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    Some(days * 86_400 + hour * 3600 + minute * 60 + second)
+}
+
+#[tokio::test]
+async fn test_error_body_has_utc_rfc3339_timestamp() {
+    let response = ApiError::not_found("User not found").into_response();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+
+    let timestamp = body["timestamp"].as_str().expect("missing timestamp");
+    let parsed = parse_rfc3339(timestamp).expect("not an RFC 3339 UTC timestamp");
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    assert!(
+        (now - parsed).abs() <= 5,
+        "{timestamp} is not the current time"
+    );
+    assert_eq!(body["message"], "User not found");
+}
@@ -0,0 +1,229 @@
+#![cfg(feature = "tracing")]
+
+use axum::body::Body;
+use axum::http::Request;
+use axum::response::IntoResponse;
+use axum::routing::post;
+use axum::Router;
+use skyak_axum_core::errors::ApiError;
+use skyak_axum_core::middleware::LogClientErrorBody;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use tower::ServiceExt;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+use tracing_core::span::Current;
+
+/// Event captured by [`Recorder`]: its level and `name=value` fields.
+#[derive(Debug, Clone)]
+struct Captured {
+    level: Level,
+    fields: Vec<(String, String)>,
+}
+
+impl Captured {
+    fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Minimal subscriber recording every event it receives and every field recorded on a span.
+#[derive(Clone, Default)]
+struct Recorder {
+    events: Arc<Mutex<Vec<Captured>>>,
+    span_fields: Arc<Mutex<Vec<(String, String)>>>,
+    entered: Arc<Mutex<Option<&'static Metadata<'static>>>>,
+    span: Arc<Mutex<Option<&'static Metadata<'static>>>>,
+}
+
+struct FieldVisitor<'a>(&'a mut Vec<(String, String)>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .push((field.name().to_string(), format!("{value:?}")));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name().to_string(), value.to_string()));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.push((field.name().to_string(), value.to_string()));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+        *self.span.lock().unwrap() = Some(attributes.metadata());
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, values: &Record<'_>) {
+        values.record(&mut FieldVisitor(&mut self.span_fields.lock().unwrap()));
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Vec::new();
+        event.record(&mut FieldVisitor(&mut fields));
+        self.events.lock().unwrap().push(Captured {
+            level: *event.metadata().level(),
+            fields,
+        });
+    }
+
+    fn enter(&self, _: &Id) {
+        *self.entered.lock().unwrap() = *self.span.lock().unwrap();
+    }
+
+    fn exit(&self, _: &Id) {
+        *self.entered.lock().unwrap() = None;
+    }
+
+    fn current_span(&self) -> Current {
+        match *self.entered.lock().unwrap() {
+            Some(metadata) => Current::new(Id::from_u64(1), metadata),
+            None => Current::none(),
+        }
+    }
+}
+
+fn capture(render: impl FnOnce()) -> Vec<Captured> {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), render);
+    let events = recorder.events.lock().unwrap().clone();
+    events
+}
+
+#[test]
+fn test_server_error_logged_at_error_level() {
+    let events = capture(|| {
+        let _ = ApiError::internal_server_error("database unreachable").into_response();
+    });
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].level, Level::ERROR);
+    assert_eq!(events[0].field("status"), Some("500"));
+    assert_eq!(events[0].field("code"), Some("internal_server_error"));
+    assert_eq!(events[0].field("message"), Some("database unreachable"));
+}
+
+#[test]
+fn test_client_error_logged_at_warn_level() {
+    let events = capture(|| {
+        let _ = ApiError::NotFound(None).into_response();
+    });
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].level, Level::WARN);
+    assert_eq!(events[0].field("status"), Some("404"));
+    assert_eq!(events[0].field("code"), Some("not_found"));
+    assert_eq!(events[0].field("message"), Some("Not Found"));
+}
+
+#[test]
+fn test_problem_response_logged_once() {
+    let events = capture(|| {
+        let _ = ApiError::BadGateway(None).into_problem_response();
+    });
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].level, Level::ERROR);
+}
+
+fn capture_span_fields(render: impl FnOnce()) -> Vec<(String, String)> {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let span = tracing::info_span!(
+            "request",
+            otel.status_code = tracing::field::Empty,
+            http.status_code = tracing::field::Empty,
+            error.type = tracing::field::Empty,
+        );
+        span.in_scope(render);
+    });
+    let fields = recorder.span_fields.lock().unwrap().clone();
+    fields
+}
+
+#[test]
+fn test_server_error_recorded_on_span() {
+    let fields = capture_span_fields(|| {
+        let _ = ApiError::internal_server_error("database unreachable").into_response();
+    });
+
+    assert!(fields.contains(&("http.status_code".to_string(), "500".to_string())));
+    assert!(fields.contains(&(
+        "error.type".to_string(),
+        "internal_server_error".to_string()
+    )));
+    assert!(fields.contains(&("otel.status_code".to_string(), "ERROR".to_string())));
+}
+
+#[test]
+fn test_client_error_leaves_span_status_unset() {
+    let fields = capture_span_fields(|| {
+        let _ = ApiError::NotFound(None).into_response();
+    });
+
+    assert!(fields.contains(&("http.status_code".to_string(), "404".to_string())));
+    assert!(!fields.iter().any(|(name, _)| name == "otel.status_code"));
+}
+
+async fn reject_bad(body: String) -> Result<&'static str, ApiError> {
+    if body.contains("bad") {
+        Err(ApiError::bad_request("Invalid payload"))
+    } else {
+        Ok("ok")
+    }
+}
+
+fn post_logged(payload: &'static str) -> Vec<Captured> {
+    let app = Router::new()
+        .route("/", post(reject_bad))
+        .layer(LogClientErrorBody::new().max_bytes(8));
+    let request = Request::builder()
+        .method("POST")
+        .uri("/")
+        .body(Body::from(payload))
+        .unwrap();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    let events = capture(|| {
+        runtime.block_on(async {
+            app.oneshot(request).await.unwrap();
+        })
+    });
+    events
+        .into_iter()
+        .filter(|event| event.field("message") == Some("request body of rejected request"))
+        .collect()
+}
+
+#[test]
+fn test_body_logged_for_client_error() {
+    let events = post_logged("a bad request body");
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].level, Level::DEBUG);
+    assert_eq!(events[0].field("status"), Some("400"));
+    assert_eq!(events[0].field("body"), Some("a bad re"));
+    assert_eq!(events[0].field("truncated"), Some("true"));
+}
+
+#[test]
+fn test_body_not_logged_for_success() {
+    assert!(post_logged("fine").is_empty());
+}